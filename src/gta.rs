@@ -1,6 +1,7 @@
 use winapi::shared::windef::HWND;
 
 pub mod camera;
+pub mod color;
 pub mod d3d9;
 pub mod d9_proxy;
 pub mod device_proxy;
@@ -12,8 +13,12 @@ pub mod menu_manager;
 pub mod object;
 pub mod physical;
 pub mod rw;
+pub mod skins;
 pub mod sprite;
+pub mod vehicles;
+pub mod weapons;
 pub mod world;
+pub mod zones;
 
 const GTA_HWND_PTR: *const HWND = 0xC97C1C as *const HWND;
 
@@ -0,0 +1,411 @@
+use super::rw::rwplcore::RwRGBA;
+
+pub const VEHICLE_COLORS: usize = 256;
+
+/// Plain 8-bit RGBA color, the common ground between samp's `D3DCOLOR` (ARGB),
+/// `0xRRGGBBAA` literals used by scripts and RenderWare's `RwRGBA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    pub const WHITE: Rgba = Rgba::new(0xFF, 0xFF, 0xFF, 0xFF);
+    pub const BLACK: Rgba = Rgba::new(0, 0, 0, 0xFF);
+    pub const TRANSPARENT: Rgba = Rgba::new(0, 0, 0, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba { r, g, b, a }
+    }
+
+    /// `0xRRGGBB`, fully opaque.
+    pub const fn from_rgb(rgb: u32) -> Rgba {
+        Rgba::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 0xFF)
+    }
+
+    /// `0xRRGGBBAA` as in `SendClientMessage` or `SetPlayerColor`.
+    pub const fn from_rgba(rgba: u32) -> Rgba {
+        Rgba::new((rgba >> 24) as u8, (rgba >> 16) as u8, (rgba >> 8) as u8, rgba as u8)
+    }
+
+    /// `D3DCOLOR`, i.e. `0xAARRGGBB`.
+    pub const fn from_argb(argb: u32) -> Rgba {
+        Rgba::new((argb >> 16) as u8, (argb >> 8) as u8, argb as u8, (argb >> 24) as u8)
+    }
+
+    /// `0xAABBGGRR`, an `RwRGBA` read as a number. Textdraws and gang zones keep colors so.
+    pub const fn from_abgr(abgr: u32) -> Rgba {
+        Rgba::new(abgr as u8, (abgr >> 8) as u8, (abgr >> 16) as u8, (abgr >> 24) as u8)
+    }
+
+    pub const fn to_rgb(&self) -> u32 {
+        (self.r as u32) << 16 | (self.g as u32) << 8 | self.b as u32
+    }
+
+    pub const fn to_rgba(&self) -> u32 {
+        (self.r as u32) << 24 | (self.g as u32) << 16 | (self.b as u32) << 8 | self.a as u32
+    }
+
+    pub const fn to_argb(&self) -> u32 {
+        (self.a as u32) << 24 | self.to_rgb()
+    }
+
+    pub const fn to_abgr(&self) -> u32 {
+        (self.a as u32) << 24 | (self.b as u32) << 16 | (self.g as u32) << 8 | self.r as u32
+    }
+
+    pub const fn with_alpha(&self, a: u8) -> Rgba {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+
+    /// Parses `0xRRGGBBAA`, `RRGGBBAA`, `RRGGBB` and `{RRGGBB}`, the last two are opaque.
+    pub fn parse(text: &str) -> Option<Rgba> {
+        let text = text.trim();
+
+        if let Some(tag) = text.strip_prefix('{').and_then(|tag| tag.strip_suffix('}')) {
+            return parse_hex(tag, 6).map(Rgba::from_rgb);
+        }
+
+        let hex = text
+            .strip_prefix("0x")
+            .or_else(|| text.strip_prefix("0X"))
+            .unwrap_or(text);
+
+        match hex.len() {
+            8 => parse_hex(hex, 8).map(Rgba::from_rgba),
+            6 => parse_hex(hex, 6).map(Rgba::from_rgb),
+            _ => None,
+        }
+    }
+
+    /// `{RRGGBB}` tag for embedding into chat or dialog text, alpha is dropped.
+    pub fn to_tag(&self) -> String {
+        format!("{{{:06X}}}", self.to_rgb())
+    }
+
+    /// Linear interpolation between `self` (`factor` = 0.0) and `other` (`factor` = 1.0).
+    pub fn lerp(&self, other: Rgba, factor: f32) -> Rgba {
+        let factor = factor.max(0.0).min(1.0);
+        let mix = |from: u8, to: u8| {
+            let value = from as f32 + (to as f32 - from as f32) * factor;
+            value.round() as u8
+        };
+
+        Rgba::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+            mix(self.a, other.a),
+        )
+    }
+
+    /// Composites `self` over `background` using straight (non-premultiplied) alpha.
+    pub fn blend_over(&self, background: Rgba) -> Rgba {
+        let src_a = self.a as f32 / 255.0;
+        let dst_a = background.a as f32 / 255.0;
+        let out_a = src_a + dst_a * (1.0 - src_a);
+
+        if out_a <= 0.0 {
+            return Rgba::TRANSPARENT;
+        }
+
+        let mix = |src: u8, dst: u8| {
+            let value = (src as f32 * src_a + dst as f32 * dst_a * (1.0 - src_a)) / out_a;
+            value.round() as u8
+        };
+
+        Rgba::new(
+            mix(self.r, background.r),
+            mix(self.g, background.g),
+            mix(self.b, background.b),
+            (out_a * 255.0).round() as u8,
+        )
+    }
+
+    /// Scales the alpha channel, e.g. for fading text out.
+    pub fn fade(&self, factor: f32) -> Rgba {
+        let alpha = self.a as f32 * factor.max(0.0).min(1.0);
+        self.with_alpha(alpha.round() as u8)
+    }
+}
+
+impl From<RwRGBA> for Rgba {
+    fn from(color: RwRGBA) -> Rgba {
+        Rgba::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+impl From<Rgba> for RwRGBA {
+    fn from(color: Rgba) -> RwRGBA {
+        RwRGBA {
+            red: color.r,
+            green: color.g,
+            blue: color.b,
+            alpha: color.a,
+        }
+    }
+}
+
+fn parse_hex(hex: &str, digits: usize) -> Option<u32> {
+    if hex.len() != digits || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// Converts a samp `D3DCOLOR` into a RenderWare color.
+pub fn d3dcolor_to_rwrgba(color: u32) -> RwRGBA {
+    Rgba::from_argb(color).into()
+}
+
+pub fn rwrgba_to_d3dcolor(color: RwRGBA) -> u32 {
+    Rgba::from(color).to_argb()
+}
+
+/// A piece of text and the color set by the last `{RRGGBB}` tag before it,
+/// `None` while no tag has been met yet (the text keeps its default color).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorSegment<'a> {
+    pub color: Option<Rgba>,
+    pub text: &'a str,
+}
+
+fn color_tag_at(text: &str, index: usize) -> Option<Rgba> {
+    let tag = text.get(index..index + 8)?;
+
+    if tag.starts_with('{') && tag.ends_with('}') {
+        parse_hex(&tag[1..7], 6).map(Rgba::from_rgb)
+    } else {
+        None
+    }
+}
+
+/// Splits text on embedded `{RRGGBB}` tags. Empty segments between adjacent tags are skipped.
+pub fn parse_color_tags(text: &str) -> Vec<ColorSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut color = None;
+    let mut start = 0;
+
+    for (index, _) in text.match_indices('{') {
+        if index < start {
+            continue;
+        }
+
+        if let Some(tag_color) = color_tag_at(text, index) {
+            if index > start {
+                segments.push(ColorSegment {
+                    color,
+                    text: &text[start..index],
+                });
+            }
+
+            color = Some(tag_color);
+            start = index + 8;
+        }
+    }
+
+    if start < text.len() {
+        segments.push(ColorSegment {
+            color,
+            text: &text[start..],
+        });
+    }
+
+    segments
+}
+
+pub fn strip_color_tags(text: &str) -> String {
+    parse_color_tags(text)
+        .iter()
+        .map(|segment| segment.text)
+        .collect()
+}
+
+/// Color of a vehicle palette index (`CVehicle::m_nPrimaryColor`/`m_nSecondaryColor`).
+/// Indices above 127 are the SA-MP extension of the single player `carcols.dat` palette.
+pub fn vehicle_color(index: u8) -> Rgba {
+    Rgba::from_rgb(VEHICLE_PALETTE[index as usize])
+}
+
+/// Palette index closest to the color, alpha is ignored.
+pub fn nearest_vehicle_color(color: Rgba) -> u8 {
+    let distance = |rgb: u32| {
+        let other = Rgba::from_rgb(rgb);
+        let dr = color.r as i32 - other.r as i32;
+        let dg = color.g as i32 - other.g as i32;
+        let db = color.b as i32 - other.b as i32;
+
+        dr * dr + dg * dg + db * db
+    };
+
+    VEHICLE_PALETTE
+        .iter()
+        .enumerate()
+        .min_by_key(|&(_, &rgb)| distance(rgb))
+        .map(|(index, _)| index as u8)
+        .unwrap_or(0)
+}
+
+static VEHICLE_PALETTE: [u32; VEHICLE_COLORS] = [
+    0x000000, 0xF5F5F5, 0x2A77A1, 0x840410, 0x263739, 0x86446E, 0xD78E10, 0x4C75B7,
+    0xBDBEC6, 0x5E7072, 0x46597A, 0x656A79, 0x5D7E8D, 0x58595A, 0xD6DAD6, 0x9CA1A3,
+    0x335F3F, 0x730E1A, 0x7B0A2A, 0x9F9D94, 0x3B4E78, 0x732E3E, 0x691E3B, 0x96918C,
+    0x515459, 0x3F3E45, 0xA5A9A7, 0x635C5A, 0x3D4A68, 0x979592, 0x421F21, 0x5F272B,
+    0x8494AB, 0x767B7C, 0x646464, 0x5A5752, 0x252527, 0x2D3A35, 0x93A396, 0x6D7A88,
+    0x221918, 0x6F675F, 0x7C1C2A, 0x5F0A15, 0x193826, 0x5D1B20, 0x9D9872, 0x7A7560,
+    0x989586, 0xADB0B0, 0x848988, 0x304F45, 0x4D6268, 0x162248, 0x272F4B, 0x7D6256,
+    0x9EA4AB, 0x9C8D71, 0x6D1822, 0x4E6881, 0x9C9C98, 0x917347, 0x661C26, 0x949D9F,
+    0xA4A7A5, 0x8E8C46, 0x341A1E, 0x6A7A8C, 0xAAAD8E, 0xAB988F, 0x851F2E, 0x6F8297,
+    0x585853, 0x9AA790, 0x601A23, 0x20202C, 0xA4A096, 0xAA9D84, 0x78222B, 0x0E316D,
+    0x722A3F, 0x7B715E, 0x741D28, 0x1E2E32, 0x4D322F, 0x7C1B44, 0x2E5B20, 0x395A83,
+    0x6D2837, 0xA7A28F, 0xAFB1B1, 0x364155, 0x6D6C6E, 0x0F6A89, 0x204B6B, 0x2B3E57,
+    0x9B9F9D, 0x6C8495, 0x4D8495, 0xAE9B7F, 0x406C8F, 0x1F253B, 0xAB9276, 0x134573,
+    0x96816C, 0x64686A, 0x105082, 0xA19983, 0x385694, 0x525661, 0x7F6956, 0x8C929A,
+    0x596E87, 0x473532, 0x44624F, 0x730A27, 0x223457, 0x640D1B, 0xA3ADC6, 0x695853,
+    0x9B8B80, 0x620B1C, 0x5B5D5E, 0x624428, 0x731827, 0x1B376D, 0xEC6AAE, 0x000000,
+    0x177517, 0x210606, 0x125478, 0x452A0D, 0x571E1E, 0x010701, 0x25225A, 0x2C89AA,
+    0x8A4DBD, 0x35963A, 0xB7B7B7, 0x464C8D, 0x84888C, 0x817867, 0x817A26, 0x6A506F,
+    0x583E6F, 0x8CB972, 0x824F78, 0x6D276A, 0x1E1D13, 0x1E1306, 0x1F2518, 0x2C4531,
+    0x1E4C99, 0x2E5F43, 0x1E9948, 0x1E9999, 0x999976, 0x7C8499, 0x992E1E, 0x2C1E08,
+    0x142407, 0x993E4D, 0x1E4C99, 0x198181, 0x1A292A, 0x16616F, 0x1B6687, 0x6C3F99,
+    0x481A0E, 0x7A7399, 0x746D99, 0x53387E, 0x222407, 0x3E190C, 0x46210E, 0x991E1E,
+    0x8D4C8D, 0x805B80, 0x7B3E7E, 0x3C1737, 0x733517, 0x781818, 0x83341A, 0x8E2F1C,
+    0x7E3E53, 0x7C6D7C, 0x020C02, 0x072407, 0x163012, 0x16301B, 0x642B4F, 0x368452,
+    0x999590, 0x818D96, 0x99991E, 0x7F994C, 0x839292, 0x788222, 0x2B3C99, 0x3A3A0B,
+    0x8A794E, 0x0E1F49, 0x15371C, 0x15273A, 0x375775, 0x060820, 0x071326, 0x20394B,
+    0x2C5089, 0x15426C, 0x103250, 0x241663, 0x692015, 0x8C8D94, 0x516013, 0x090F02,
+    0x8C573A, 0x52888E, 0x995C52, 0x99581E, 0x993A63, 0x998F4E, 0x99311E, 0x0D1842,
+    0x521E1E, 0x42420D, 0x4C991E, 0x082A1D, 0x96821D, 0x197F19, 0x3B141F, 0x745217,
+    0x893F8D, 0x7E1A6C, 0x0B370B, 0x27450D, 0x071F24, 0x784573, 0x8A653A, 0x732617,
+    0x319490, 0x56941D, 0x59163D, 0x1B8A2F, 0x38160B, 0x041804, 0x355D8E, 0x2E3F5B,
+    0x561A28, 0x4E0E27, 0x706C67, 0x3B3E42, 0x2E2D33, 0x7B7E7D, 0x4A4442, 0x28344E,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOR: Rgba = Rgba::new(0x11, 0x22, 0x33, 0x44);
+
+    fn segment(color: Option<u32>, text: &str) -> ColorSegment<'_> {
+        ColorSegment {
+            color: color.map(Rgba::from_rgb),
+            text,
+        }
+    }
+
+    #[test]
+    fn packed_formats() {
+        assert_eq!(COLOR.to_rgb(), 0x112233);
+        assert_eq!(COLOR.to_rgba(), 0x11223344);
+        assert_eq!(COLOR.to_argb(), 0x44112233);
+        assert_eq!(COLOR.to_abgr(), 0x44332211);
+
+        assert_eq!(Rgba::from_rgb(0x112233), COLOR.with_alpha(0xFF));
+        assert_eq!(Rgba::from_rgba(0x11223344), COLOR);
+        assert_eq!(Rgba::from_argb(0x44112233), COLOR);
+        assert_eq!(Rgba::from_abgr(0x44332211), COLOR);
+    }
+
+    #[test]
+    fn rwrgba_conversions() {
+        let rw = RwRGBA::from(COLOR);
+        assert_eq!((rw.red, rw.green, rw.blue, rw.alpha), (0x11, 0x22, 0x33, 0x44));
+        assert_eq!(Rgba::from(rw), COLOR);
+
+        let rw = d3dcolor_to_rwrgba(0x44112233);
+        assert_eq!((rw.red, rw.green, rw.blue, rw.alpha), (0x11, 0x22, 0x33, 0x44));
+        assert_eq!(rwrgba_to_d3dcolor(rw), 0x44112233);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Rgba::parse("0x11223344"), Some(COLOR));
+        assert_eq!(Rgba::parse("0X11223344"), Some(COLOR));
+        assert_eq!(Rgba::parse(" 11223344 "), Some(COLOR));
+        assert_eq!(Rgba::parse("112233"), Some(COLOR.with_alpha(0xFF)));
+        assert_eq!(Rgba::parse("{112233}"), Some(COLOR.with_alpha(0xFF)));
+
+        assert_eq!(Rgba::parse(""), None);
+        assert_eq!(Rgba::parse("0x"), None);
+        assert_eq!(Rgba::parse("0x1122"), None);
+        assert_eq!(Rgba::parse("0x1122334455"), None);
+        assert_eq!(Rgba::parse("0xGG223344"), None);
+        assert_eq!(Rgba::parse("+1223344"), None);
+        assert_eq!(Rgba::parse("{11223344}"), None);
+        assert_eq!(Rgba::parse("{112233"), None);
+    }
+
+    #[test]
+    fn tags() {
+        assert_eq!(COLOR.to_tag(), "{112233}");
+        assert_eq!(
+            parse_color_tags("plain {FF0000}red{00ff00}green"),
+            vec![
+                segment(None, "plain "),
+                segment(Some(0xFF0000), "red"),
+                segment(Some(0x00FF00), "green"),
+            ]
+        );
+        assert_eq!(
+            parse_color_tags("{FF0000}{0000FF}blue"),
+            vec![segment(Some(0x0000FF), "blue")]
+        );
+        assert_eq!(strip_color_tags("a{FF0000}b{00FF00}c"), "abc");
+    }
+
+    #[test]
+    fn malformed_tags() {
+        assert!(parse_color_tags("").is_empty());
+        assert!(parse_color_tags("{FF0000}").is_empty());
+
+        for text in ["{FF0000", "text {FF00", "{FFF}short", "{GG0000}hex", "{FF 000}x", "{}"] {
+            assert_eq!(parse_color_tags(text), vec![segment(None, text)]);
+        }
+
+        assert_eq!(parse_color_tags("end{FF0000}"), vec![segment(None, "end")]);
+        assert_eq!(
+            parse_color_tags("{{FF0000}x"),
+            vec![segment(None, "{"), segment(Some(0xFF0000), "x")]
+        );
+        assert_eq!(
+            parse_color_tags("ä{FF0000}ö"),
+            vec![segment(None, "ä"), segment(Some(0xFF0000), "ö")]
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        assert_eq!(Rgba::BLACK.lerp(Rgba::WHITE, 0.0), Rgba::BLACK);
+        assert_eq!(Rgba::BLACK.lerp(Rgba::WHITE, 1.0), Rgba::WHITE);
+        assert_eq!(
+            Rgba::BLACK.lerp(Rgba::WHITE, 0.5),
+            Rgba::new(0x80, 0x80, 0x80, 0xFF)
+        );
+        assert_eq!(Rgba::BLACK.lerp(Rgba::WHITE, -1.0), Rgba::BLACK);
+        assert_eq!(Rgba::BLACK.lerp(Rgba::WHITE, 2.0), Rgba::WHITE);
+        assert_eq!(COLOR.lerp(Rgba::TRANSPARENT, 1.0), Rgba::TRANSPARENT);
+    }
+
+    #[test]
+    fn blend_over() {
+        let red = Rgba::new(0xFF, 0, 0, 0xFF);
+
+        assert_eq!(red.blend_over(Rgba::WHITE), red);
+        assert_eq!(Rgba::TRANSPARENT.blend_over(COLOR), COLOR);
+        assert_eq!(Rgba::TRANSPARENT.blend_over(Rgba::TRANSPARENT), Rgba::TRANSPARENT);
+        assert_eq!(
+            red.with_alpha(0x80).blend_over(Rgba::WHITE),
+            Rgba::new(0xFF, 0x7F, 0x7F, 0xFF)
+        );
+        assert_eq!(
+            red.with_alpha(0x80).blend_over(Rgba::TRANSPARENT),
+            red.with_alpha(0x80)
+        );
+        assert_eq!(Rgba::WHITE.fade(0.5), Rgba::WHITE.with_alpha(0x80));
+    }
+}
@@ -58,6 +58,20 @@ impl CVector {
             z: self.z + z,
         }
     }
+
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn normalized(&self) -> CVector {
+        let length = self.length();
+
+        if length > 0.0 {
+            CVector::new(self.x / length, self.y / length, self.z / length)
+        } else {
+            CVector::zero()
+        }
+    }
 }
 
 #[repr(C, packed)]
@@ -0,0 +1,367 @@
+use Gang::*;
+use Gender::*;
+
+pub const MAX_SKIN_ID: u16 = 311;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gang {
+    GroveStreet,
+    Ballas,
+    Vagos,
+    Aztecas,
+    Rifa,
+    Triads,
+    DaNangBoys,
+    RussianMafia,
+    Mafia,
+}
+
+#[derive(Debug)]
+pub struct Skin {
+    pub id: u16,
+    pub name: &'static str,
+    pub gender: Gender,
+    pub gang: Option<Gang>,
+}
+
+const fn skin(id: u16, name: &'static str, gender: Gender, gang: Option<Gang>) -> Skin {
+    Skin {
+        id,
+        name,
+        gender,
+        gang,
+    }
+}
+
+pub fn skin_info(skin: i32) -> Option<&'static Skin> {
+    if skin < 0 || skin > MAX_SKIN_ID as i32 {
+        return None;
+    }
+
+    SKINS.get(skin as usize)
+}
+
+pub fn skins() -> impl Iterator<Item = &'static Skin> {
+    SKINS.iter()
+}
+
+static SKINS: [Skin; 312] = [
+    skin(0, "Carl \"CJ\" Johnson", Male, Some(GroveStreet)),
+    skin(1, "The Truth", Male, None),
+    skin(2, "Maccer", Male, None),
+    skin(3, "Andre", Male, None),
+    skin(4, "Barry \"Big Bear\" Thorne (Thin)", Male, None),
+    skin(5, "Barry \"Big Bear\" Thorne (Big)", Male, None),
+    skin(6, "Emmet", Male, None),
+    skin(7, "Taxi Driver", Male, None),
+    skin(8, "Janitor", Male, None),
+    skin(9, "Normal Ped", Female, None),
+    skin(10, "Old Woman", Female, None),
+    skin(11, "Casino Croupier", Female, None),
+    skin(12, "Rich Woman", Female, None),
+    skin(13, "Street Girl", Female, None),
+    skin(14, "Normal Ped", Male, None),
+    skin(15, "Mr. Whittaker", Male, None),
+    skin(16, "Airport Ground Worker", Male, None),
+    skin(17, "Businessman", Male, None),
+    skin(18, "Beach Visitor", Male, None),
+    skin(19, "DJ", Male, None),
+    skin(20, "Rich Guy", Male, None),
+    skin(21, "Normal Ped", Male, None),
+    skin(22, "Normal Ped", Male, None),
+    skin(23, "BMXer", Male, None),
+    skin(24, "Madd Dogg Bodyguard", Male, None),
+    skin(25, "Madd Dogg Bodyguard", Male, None),
+    skin(26, "Backpacker", Male, None),
+    skin(27, "Construction Worker", Male, None),
+    skin(28, "Drug Dealer", Male, None),
+    skin(29, "Drug Dealer", Male, None),
+    skin(30, "Drug Dealer", Male, None),
+    skin(31, "Farm-Town Inhabitant", Female, None),
+    skin(32, "Farm-Town Inhabitant", Male, None),
+    skin(33, "Farm-Town Inhabitant", Male, None),
+    skin(34, "Farm-Town Inhabitant", Male, None),
+    skin(35, "Gardener", Male, None),
+    skin(36, "Golfer", Male, None),
+    skin(37, "Golfer", Male, None),
+    skin(38, "Normal Ped", Female, None),
+    skin(39, "Normal Ped", Female, None),
+    skin(40, "Normal Ped", Female, None),
+    skin(41, "Normal Ped", Female, None),
+    skin(42, "Jethro", Male, None),
+    skin(43, "Normal Ped", Male, None),
+    skin(44, "Normal Ped", Male, None),
+    skin(45, "Beach Visitor", Male, None),
+    skin(46, "Normal Ped", Male, None),
+    skin(47, "Normal Ped", Male, None),
+    skin(48, "Normal Ped", Male, None),
+    skin(49, "Snakehead", Male, Some(DaNangBoys)),
+    skin(50, "Mechanic", Male, None),
+    skin(51, "Mountain Biker", Male, None),
+    skin(52, "Mountain Biker", Male, None),
+    skin(53, "Unknown", Female, None),
+    skin(54, "Normal Ped", Female, None),
+    skin(55, "Normal Ped", Female, None),
+    skin(56, "Normal Ped", Female, None),
+    skin(57, "Oriental Ped", Male, None),
+    skin(58, "Oriental Ped", Male, None),
+    skin(59, "Normal Ped", Male, None),
+    skin(60, "Normal Ped", Male, None),
+    skin(61, "Pilot", Male, None),
+    skin(62, "Colonel Fuhrberger", Male, None),
+    skin(63, "Prostitute", Female, None),
+    skin(64, "Prostitute", Female, None),
+    skin(65, "Kendl Johnson", Female, Some(GroveStreet)),
+    skin(66, "Pool Player", Male, None),
+    skin(67, "Pool Player", Male, None),
+    skin(68, "Priest", Male, None),
+    skin(69, "Normal Ped", Female, None),
+    skin(70, "Scientist", Male, None),
+    skin(71, "Security Guard", Male, None),
+    skin(72, "Hippy", Male, None),
+    skin(73, "Hippy", Male, None),
+    skin(74, "Unused", Male, None),
+    skin(75, "Prostitute", Female, None),
+    skin(76, "Stewardess", Female, None),
+    skin(77, "Homeless", Female, None),
+    skin(78, "Homeless", Male, None),
+    skin(79, "Homeless", Male, None),
+    skin(80, "Boxer", Male, None),
+    skin(81, "Boxer", Male, None),
+    skin(82, "Black Elvis", Male, None),
+    skin(83, "White Elvis", Male, None),
+    skin(84, "Blue Elvis", Male, None),
+    skin(85, "Prostitute", Female, None),
+    skin(86, "Ryder with Robbery Mask", Male, Some(GroveStreet)),
+    skin(87, "Stripper", Female, None),
+    skin(88, "Normal Ped", Female, None),
+    skin(89, "Normal Ped", Female, None),
+    skin(90, "Jogger", Female, None),
+    skin(91, "Rich Woman", Female, None),
+    skin(92, "Rollerskater", Female, None),
+    skin(93, "Normal Ped", Female, None),
+    skin(94, "Normal Ped", Male, None),
+    skin(95, "Normal Ped", Male, None),
+    skin(96, "Jogger", Male, None),
+    skin(97, "Lifeguard", Male, None),
+    skin(98, "Normal Ped", Male, None),
+    skin(99, "Rollerskater", Male, None),
+    skin(100, "Biker", Male, None),
+    skin(101, "Normal Ped", Male, None),
+    skin(102, "Balla", Male, Some(Ballas)),
+    skin(103, "Balla", Male, Some(Ballas)),
+    skin(104, "Balla", Male, Some(Ballas)),
+    skin(105, "Grove Street Families", Male, Some(GroveStreet)),
+    skin(106, "Grove Street Families", Male, Some(GroveStreet)),
+    skin(107, "Grove Street Families", Male, Some(GroveStreet)),
+    skin(108, "Los Santos Vagos", Male, Some(Vagos)),
+    skin(109, "Los Santos Vagos", Male, Some(Vagos)),
+    skin(110, "Los Santos Vagos", Male, Some(Vagos)),
+    skin(111, "The Russian Mafia", Male, Some(RussianMafia)),
+    skin(112, "The Russian Mafia", Male, Some(RussianMafia)),
+    skin(113, "The Russian Mafia Boss", Male, Some(RussianMafia)),
+    skin(114, "Varrios Los Aztecas", Male, Some(Aztecas)),
+    skin(115, "Varrios Los Aztecas", Male, Some(Aztecas)),
+    skin(116, "Varrios Los Aztecas", Male, Some(Aztecas)),
+    skin(117, "Triad", Male, Some(Triads)),
+    skin(118, "Triad", Male, Some(Triads)),
+    skin(119, "Johnny Sindacco", Male, Some(Mafia)),
+    skin(120, "Triad Boss", Male, Some(Triads)),
+    skin(121, "Da Nang Boy", Male, Some(DaNangBoys)),
+    skin(122, "Da Nang Boy", Male, Some(DaNangBoys)),
+    skin(123, "Da Nang Boy", Male, Some(DaNangBoys)),
+    skin(124, "The Mafia", Male, Some(Mafia)),
+    skin(125, "The Mafia", Male, Some(Mafia)),
+    skin(126, "The Mafia", Male, Some(Mafia)),
+    skin(127, "The Mafia", Male, Some(Mafia)),
+    skin(128, "Farm Inhabitant", Male, None),
+    skin(129, "Farm Inhabitant", Female, None),
+    skin(130, "Farm Inhabitant", Female, None),
+    skin(131, "Farm Inhabitant", Female, None),
+    skin(132, "Farm Inhabitant", Male, None),
+    skin(133, "Farm Inhabitant", Male, None),
+    skin(134, "Homeless", Male, None),
+    skin(135, "Homeless", Male, None),
+    skin(136, "Normal Ped", Male, None),
+    skin(137, "Homeless", Male, None),
+    skin(138, "Beach Visitor", Female, None),
+    skin(139, "Beach Visitor", Female, None),
+    skin(140, "Beach Visitor", Female, None),
+    skin(141, "Businesswoman", Female, None),
+    skin(142, "Taxi Driver", Male, None),
+    skin(143, "Crack Maker", Male, None),
+    skin(144, "Crack Maker", Male, None),
+    skin(145, "Crack Maker", Female, None),
+    skin(146, "Crack Maker", Male, None),
+    skin(147, "Businessman", Male, None),
+    skin(148, "Businesswoman", Female, None),
+    skin(149, "Big Smoke Armored", Male, Some(GroveStreet)),
+    skin(150, "Businesswoman", Female, None),
+    skin(151, "Normal Ped", Female, None),
+    skin(152, "Prostitute", Female, None),
+    skin(153, "Construction Worker", Male, None),
+    skin(154, "Beach Visitor", Male, None),
+    skin(155, "Well Stacked Pizza Worker", Male, None),
+    skin(156, "Barber", Male, None),
+    skin(157, "Hillbilly", Female, None),
+    skin(158, "Farmer", Male, None),
+    skin(159, "Hillbilly", Male, None),
+    skin(160, "Hillbilly", Male, None),
+    skin(161, "Farmer", Male, None),
+    skin(162, "Hillbilly", Male, None),
+    skin(163, "Black Bouncer", Male, None),
+    skin(164, "White Bouncer", Male, None),
+    skin(165, "White MIB Agent", Male, None),
+    skin(166, "Black MIB Agent", Male, None),
+    skin(167, "Cluckin' Bell Security", Male, None),
+    skin(168, "Hotdog Vendor", Male, None),
+    skin(169, "Normal Ped", Female, None),
+    skin(170, "Normal Ped", Male, None),
+    skin(171, "Blackjack Dealer", Male, None),
+    skin(172, "Casino Croupier", Female, None),
+    skin(173, "San Fierro Rifa", Male, Some(Rifa)),
+    skin(174, "San Fierro Rifa", Male, Some(Rifa)),
+    skin(175, "San Fierro Rifa", Male, Some(Rifa)),
+    skin(176, "Barber", Male, None),
+    skin(177, "Barber", Male, None),
+    skin(178, "Whore", Female, None),
+    skin(179, "Ammunation Salesman", Male, None),
+    skin(180, "Tattoo Artist", Male, None),
+    skin(181, "Punk", Male, None),
+    skin(182, "Cab Driver", Male, None),
+    skin(183, "Normal Ped", Male, None),
+    skin(184, "Normal Ped", Male, None),
+    skin(185, "Normal Ped", Male, None),
+    skin(186, "Normal Ped", Male, None),
+    skin(187, "Businessman", Male, None),
+    skin(188, "Normal Ped", Male, None),
+    skin(189, "Valet", Male, None),
+    skin(190, "Barbara Schternvart", Female, None),
+    skin(191, "Helena Wankstein", Female, None),
+    skin(192, "Michelle Cannes", Female, None),
+    skin(193, "Katie Zhan", Female, None),
+    skin(194, "Millie Perkins", Female, None),
+    skin(195, "Denise Robinson", Female, None),
+    skin(196, "Farm-Town Inhabitant", Female, None),
+    skin(197, "Hillbilly", Female, None),
+    skin(198, "Farm-Town Inhabitant", Female, None),
+    skin(199, "Farm-Town Inhabitant", Female, None),
+    skin(200, "Hillbilly", Male, None),
+    skin(201, "Farmer", Female, None),
+    skin(202, "Farmer", Male, None),
+    skin(203, "Karate Teacher", Male, None),
+    skin(204, "Karate Teacher", Male, None),
+    skin(205, "Burger Shot Cashier", Female, None),
+    skin(206, "Cab Driver", Male, None),
+    skin(207, "Prostitute", Female, None),
+    skin(208, "Su Xi Mu", Male, Some(Triads)),
+    skin(209, "Noodle Stand Vendor", Male, None),
+    skin(210, "Boating School Instructor", Male, None),
+    skin(211, "Clothes Shop Staff", Female, None),
+    skin(212, "Homeless", Male, None),
+    skin(213, "Weird Old Man", Male, None),
+    skin(214, "Waitress (Maria Latore)", Female, None),
+    skin(215, "Normal Ped", Female, None),
+    skin(216, "Normal Ped", Female, None),
+    skin(217, "Clothes Shop Staff", Male, None),
+    skin(218, "Normal Ped", Female, None),
+    skin(219, "Rich Woman", Female, None),
+    skin(220, "Cab Driver", Male, None),
+    skin(221, "Normal Ped", Male, None),
+    skin(222, "Normal Ped", Male, None),
+    skin(223, "Normal Ped", Male, None),
+    skin(224, "Normal Ped", Female, None),
+    skin(225, "Normal Ped", Female, None),
+    skin(226, "Normal Ped", Female, None),
+    skin(227, "Oriental Businessman", Male, None),
+    skin(228, "Oriental Ped", Male, None),
+    skin(229, "Oriental Ped", Male, None),
+    skin(230, "Homeless", Male, None),
+    skin(231, "Normal Ped", Female, None),
+    skin(232, "Normal Ped", Female, None),
+    skin(233, "Normal Ped", Female, None),
+    skin(234, "Cab Driver", Male, None),
+    skin(235, "Normal Ped", Male, None),
+    skin(236, "Normal Ped", Male, None),
+    skin(237, "Prostitute", Female, None),
+    skin(238, "Prostitute", Female, None),
+    skin(239, "Homeless", Male, None),
+    skin(240, "The D.A.", Male, None),
+    skin(241, "Afro-American", Male, None),
+    skin(242, "Mexican", Male, None),
+    skin(243, "Prostitute", Female, None),
+    skin(244, "Stripper", Female, None),
+    skin(245, "Prostitute", Female, None),
+    skin(246, "Stripper", Female, None),
+    skin(247, "Biker", Male, None),
+    skin(248, "Biker", Male, None),
+    skin(249, "Pimp", Male, None),
+    skin(250, "Normal Ped", Male, None),
+    skin(251, "Lifeguard", Female, None),
+    skin(252, "Naked Valet", Male, None),
+    skin(253, "Bus Driver", Male, None),
+    skin(254, "Biker Drug Dealer", Male, None),
+    skin(255, "Chauffeur", Male, None),
+    skin(256, "Stripper", Female, None),
+    skin(257, "Stripper", Female, None),
+    skin(258, "Heckler", Male, None),
+    skin(259, "Heckler", Male, None),
+    skin(260, "Construction Worker", Male, None),
+    skin(261, "Cab Driver", Male, None),
+    skin(262, "Cab Driver", Male, None),
+    skin(263, "Normal Ped", Female, None),
+    skin(264, "Clown", Male, None),
+    skin(265, "Officer Frank Tenpenny", Male, None),
+    skin(266, "Officer Eddie Pulaski", Male, None),
+    skin(267, "Officer Jimmy Hernandez", Male, None),
+    skin(268, "Dwaine", Male, None),
+    skin(269, "Melvin \"Big Smoke\" Harris", Male, Some(GroveStreet)),
+    skin(270, "Sean \"Sweet\" Johnson", Male, Some(GroveStreet)),
+    skin(271, "Lance \"Ryder\" Wilson", Male, Some(GroveStreet)),
+    skin(272, "Mafia Boss", Male, Some(Mafia)),
+    skin(273, "T-Bone Mendez", Male, Some(Rifa)),
+    skin(274, "Paramedic", Male, None),
+    skin(275, "Paramedic", Male, None),
+    skin(276, "Paramedic", Male, None),
+    skin(277, "Firefighter", Male, None),
+    skin(278, "Firefighter", Male, None),
+    skin(279, "Firefighter", Male, None),
+    skin(280, "Los Santos Police Officer", Male, None),
+    skin(281, "San Fierro Police Officer", Male, None),
+    skin(282, "Las Venturas Police Officer", Male, None),
+    skin(283, "County Sheriff", Male, None),
+    skin(284, "LSPD Motorbike Cop", Male, None),
+    skin(285, "S.W.A.T. Special Forces", Male, None),
+    skin(286, "Federal Agent", Male, None),
+    skin(287, "San Andreas Army", Male, None),
+    skin(288, "Desert Sheriff", Male, None),
+    skin(289, "Zero", Male, None),
+    skin(290, "Ken Rosenberg", Male, None),
+    skin(291, "Kent Paul", Male, None),
+    skin(292, "Cesar Vialpando", Male, Some(Aztecas)),
+    skin(293, "Jeffery \"OG Loc\" Martin", Male, Some(GroveStreet)),
+    skin(294, "Wu Zi Mu (Woozie)", Male, Some(Triads)),
+    skin(295, "Michael Toreno", Male, None),
+    skin(296, "Jizzy B.", Male, None),
+    skin(297, "Madd Dogg", Male, None),
+    skin(298, "Catalina", Female, None),
+    skin(299, "Claude Speed", Male, None),
+    skin(300, "Los Santos Police Officer (No Holster)", Male, None),
+    skin(301, "San Fierro Police Officer (No Holster)", Male, None),
+    skin(302, "Las Venturas Police Officer (No Holster)", Male, None),
+    skin(303, "Los Santos Police Officer (No Uniform)", Male, None),
+    skin(304, "Los Santos Police Officer (No Uniform)", Male, None),
+    skin(305, "Las Venturas Police Officer (No Uniform)", Male, None),
+    skin(306, "Los Santos Police Officer", Female, None),
+    skin(307, "San Fierro Police Officer", Female, None),
+    skin(308, "San Fierro Paramedic", Female, None),
+    skin(309, "Las Venturas Police Officer", Female, None),
+    skin(310, "County Sheriff (No Hat)", Male, None),
+    skin(311, "Desert Sheriff (No Hat)", Male, None),
+];
@@ -0,0 +1,303 @@
+use VehicleClass::*;
+
+pub const FIRST_VEHICLE_MODEL: u16 = 400;
+pub const LAST_VEHICLE_MODEL: u16 = 611;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VehicleClass {
+    Airplane,
+    Helicopter,
+    Bike,
+    Convertible,
+    Industrial,
+    Lowrider,
+    OffRoad,
+    PublicService,
+    Saloon,
+    Sport,
+    StationWagon,
+    Boat,
+    Trailer,
+    Unique,
+    Rc,
+}
+
+#[derive(Debug)]
+pub struct VehicleModel {
+    pub id: u16,
+    pub name: &'static str,
+    pub class: VehicleClass,
+    /// Seats including the driver, zero for trailers.
+    pub seats: u8,
+    pub has_siren: bool,
+    pub is_trailer: bool,
+}
+
+impl VehicleModel {
+    pub fn passengers(&self) -> u8 {
+        self.seats.saturating_sub(1)
+    }
+
+    pub fn is_aircraft(&self) -> bool {
+        self.class == Airplane || self.class == Helicopter
+    }
+}
+
+const fn model(
+    id: u16,
+    name: &'static str,
+    class: VehicleClass,
+    seats: u8,
+    has_siren: bool,
+    is_trailer: bool,
+) -> VehicleModel {
+    VehicleModel {
+        id,
+        name,
+        class,
+        seats,
+        has_siren,
+        is_trailer,
+    }
+}
+
+pub fn vehicle_model(model: i32) -> Option<&'static VehicleModel> {
+    if model < FIRST_VEHICLE_MODEL as i32 || model > LAST_VEHICLE_MODEL as i32 {
+        return None;
+    }
+
+    VEHICLE_MODELS.get((model - FIRST_VEHICLE_MODEL as i32) as usize)
+}
+
+/// Exact name match first, then the first model whose name contains `name` (case insensitive).
+pub fn find_vehicle_model(name: &str) -> Option<&'static VehicleModel> {
+    let name = name.to_lowercase();
+
+    VEHICLE_MODELS
+        .iter()
+        .find(|model| model.name.to_lowercase() == name)
+        .or_else(|| {
+            VEHICLE_MODELS
+                .iter()
+                .find(|model| model.name.to_lowercase().contains(&name))
+        })
+}
+
+pub fn vehicle_models() -> impl Iterator<Item = &'static VehicleModel> {
+    VEHICLE_MODELS.iter()
+}
+
+static VEHICLE_MODELS: [VehicleModel; 212] = [
+    model(400, "Landstalker", OffRoad, 4, false, false),
+    model(401, "Bravura", Saloon, 2, false, false),
+    model(402, "Buffalo", Sport, 2, false, false),
+    model(403, "Linerunner", Industrial, 2, false, false),
+    model(404, "Perenniel", StationWagon, 4, false, false),
+    model(405, "Sentinel", Saloon, 4, false, false),
+    model(406, "Dumper", Unique, 1, false, false),
+    model(407, "Firetruck", PublicService, 2, true, false),
+    model(408, "Trashmaster", Industrial, 2, false, false),
+    model(409, "Stretch", Unique, 4, false, false),
+    model(410, "Manana", Saloon, 2, false, false),
+    model(411, "Infernus", Sport, 2, false, false),
+    model(412, "Voodoo", Lowrider, 2, false, false),
+    model(413, "Pony", Industrial, 4, false, false),
+    model(414, "Mule", Industrial, 2, false, false),
+    model(415, "Cheetah", Sport, 2, false, false),
+    model(416, "Ambulance", PublicService, 4, true, false),
+    model(417, "Leviathan", Helicopter, 2, false, false),
+    model(418, "Moonbeam", StationWagon, 4, false, false),
+    model(419, "Esperanto", Saloon, 2, false, false),
+    model(420, "Taxi", PublicService, 4, false, false),
+    model(421, "Washington", Saloon, 4, false, false),
+    model(422, "Bobcat", Industrial, 2, false, false),
+    model(423, "Mr Whoopee", Unique, 2, false, false),
+    model(424, "BF Injection", OffRoad, 2, false, false),
+    model(425, "Hunter", Helicopter, 1, false, false),
+    model(426, "Premier", Saloon, 4, false, false),
+    model(427, "Enforcer", PublicService, 4, true, false),
+    model(428, "Securicar", Unique, 4, false, false),
+    model(429, "Banshee", Sport, 2, false, false),
+    model(430, "Predator", Boat, 1, true, false),
+    model(431, "Bus", PublicService, 9, false, false),
+    model(432, "Rhino", PublicService, 1, false, false),
+    model(433, "Barracks", PublicService, 2, false, false),
+    model(434, "Hotknife", Sport, 2, false, false),
+    model(435, "Article Trailer", Trailer, 0, false, true),
+    model(436, "Previon", Saloon, 2, false, false),
+    model(437, "Coach", PublicService, 9, false, false),
+    model(438, "Cabbie", PublicService, 4, false, false),
+    model(439, "Stallion", Convertible, 2, false, false),
+    model(440, "Rumpo", Industrial, 4, false, false),
+    model(441, "RC Bandit", Rc, 1, false, false),
+    model(442, "Romero", Unique, 2, false, false),
+    model(443, "Packer", Industrial, 2, false, false),
+    model(444, "Monster", OffRoad, 2, false, false),
+    model(445, "Admiral", Saloon, 4, false, false),
+    model(446, "Squalo", Boat, 1, false, false),
+    model(447, "Seasparrow", Helicopter, 2, false, false),
+    model(448, "Pizzaboy", Bike, 1, false, false),
+    model(449, "Tram", Unique, 1, false, false),
+    model(450, "Article Trailer 2", Trailer, 0, false, true),
+    model(451, "Turismo", Sport, 2, false, false),
+    model(452, "Speeder", Boat, 1, false, false),
+    model(453, "Reefer", Boat, 1, false, false),
+    model(454, "Tropic", Boat, 1, false, false),
+    model(455, "Flatbed", Industrial, 2, false, false),
+    model(456, "Yankee", Industrial, 2, false, false),
+    model(457, "Caddy", Unique, 2, false, false),
+    model(458, "Solair", StationWagon, 4, false, false),
+    model(459, "Berkley's RC Van", Industrial, 4, false, false),
+    model(460, "Skimmer", Airplane, 2, false, false),
+    model(461, "PCJ-600", Bike, 2, false, false),
+    model(462, "Faggio", Bike, 2, false, false),
+    model(463, "Freeway", Bike, 2, false, false),
+    model(464, "RC Baron", Rc, 1, false, false),
+    model(465, "RC Raider", Rc, 1, false, false),
+    model(466, "Glendale", Saloon, 4, false, false),
+    model(467, "Oceanic", Saloon, 4, false, false),
+    model(468, "Sanchez", Bike, 2, false, false),
+    model(469, "Sparrow", Helicopter, 2, false, false),
+    model(470, "Patriot", OffRoad, 4, false, false),
+    model(471, "Quad", Bike, 2, false, false),
+    model(472, "Coastguard", Boat, 1, false, false),
+    model(473, "Dinghy", Boat, 1, false, false),
+    model(474, "Hermes", Saloon, 2, false, false),
+    model(475, "Sabre", Sport, 2, false, false),
+    model(476, "Rustler", Airplane, 1, false, false),
+    model(477, "ZR-350", Sport, 2, false, false),
+    model(478, "Walton", Industrial, 2, false, false),
+    model(479, "Regina", StationWagon, 4, false, false),
+    model(480, "Comet", Convertible, 2, false, false),
+    model(481, "BMX", Bike, 1, false, false),
+    model(482, "Burrito", Industrial, 4, false, false),
+    model(483, "Camper", Unique, 3, false, false),
+    model(484, "Marquis", Boat, 1, false, false),
+    model(485, "Baggage", Unique, 1, false, false),
+    model(486, "Dozer", Unique, 1, false, false),
+    model(487, "Maverick", Helicopter, 4, false, false),
+    model(488, "News Chopper", Helicopter, 2, false, false),
+    model(489, "Rancher", OffRoad, 2, false, false),
+    model(490, "FBI Rancher", PublicService, 4, true, false),
+    model(491, "Virgo", Saloon, 2, false, false),
+    model(492, "Greenwood", Saloon, 4, false, false),
+    model(493, "Jetmax", Boat, 1, false, false),
+    model(494, "Hotring Racer", Sport, 2, false, false),
+    model(495, "Sandking", OffRoad, 2, false, false),
+    model(496, "Blista Compact", Sport, 2, false, false),
+    model(497, "Police Maverick", Helicopter, 4, true, false),
+    model(498, "Boxville", Industrial, 4, false, false),
+    model(499, "Benson", Industrial, 2, false, false),
+    model(500, "Mesa", OffRoad, 2, false, false),
+    model(501, "RC Goblin", Rc, 1, false, false),
+    model(502, "Hotring Racer A", Sport, 2, false, false),
+    model(503, "Hotring Racer B", Sport, 2, false, false),
+    model(504, "Bloodring Banger", Saloon, 2, false, false),
+    model(505, "Rancher Lure", OffRoad, 2, false, false),
+    model(506, "Super GT", Sport, 2, false, false),
+    model(507, "Elegant", Saloon, 4, false, false),
+    model(508, "Journey", Unique, 2, false, false),
+    model(509, "Bike", Bike, 1, false, false),
+    model(510, "Mountain Bike", Bike, 1, false, false),
+    model(511, "Beagle", Airplane, 2, false, false),
+    model(512, "Cropduster", Airplane, 1, false, false),
+    model(513, "Stuntplane", Airplane, 1, false, false),
+    model(514, "Tanker", Industrial, 2, false, false),
+    model(515, "Roadtrain", Industrial, 2, false, false),
+    model(516, "Nebula", Saloon, 4, false, false),
+    model(517, "Majestic", Saloon, 2, false, false),
+    model(518, "Buccaneer", Saloon, 2, false, false),
+    model(519, "Shamal", Airplane, 1, false, false),
+    model(520, "Hydra", Airplane, 1, false, false),
+    model(521, "FCR-900", Bike, 2, false, false),
+    model(522, "NRG-500", Bike, 2, false, false),
+    model(523, "HPV1000", PublicService, 2, true, false),
+    model(524, "Cement Truck", Industrial, 2, false, false),
+    model(525, "Towtruck", Unique, 2, false, false),
+    model(526, "Fortune", Saloon, 2, false, false),
+    model(527, "Cadrona", Saloon, 2, false, false),
+    model(528, "FBI Truck", PublicService, 2, true, false),
+    model(529, "Willard", Saloon, 4, false, false),
+    model(530, "Forklift", Unique, 1, false, false),
+    model(531, "Tractor", Industrial, 1, false, false),
+    model(532, "Combine Harvester", Unique, 1, false, false),
+    model(533, "Feltzer", Convertible, 2, false, false),
+    model(534, "Remington", Lowrider, 2, false, false),
+    model(535, "Slamvan", Lowrider, 2, false, false),
+    model(536, "Blade", Lowrider, 2, false, false),
+    model(537, "Freight", Unique, 1, false, false),
+    model(538, "Brown Streak", Unique, 1, false, false),
+    model(539, "Vortex", Unique, 1, false, false),
+    model(540, "Vincent", Saloon, 4, false, false),
+    model(541, "Bullet", Sport, 2, false, false),
+    model(542, "Clover", Saloon, 2, false, false),
+    model(543, "Sadler", Industrial, 2, false, false),
+    model(544, "Firetruck LA", PublicService, 2, true, false),
+    model(545, "Hustler", Unique, 2, false, false),
+    model(546, "Intruder", Saloon, 4, false, false),
+    model(547, "Primo", Saloon, 4, false, false),
+    model(548, "Cargobob", Helicopter, 2, false, false),
+    model(549, "Tampa", Saloon, 2, false, false),
+    model(550, "Sunrise", Saloon, 4, false, false),
+    model(551, "Merit", Saloon, 4, false, false),
+    model(552, "Utility Van", Industrial, 2, false, false),
+    model(553, "Nevada", Airplane, 1, false, false),
+    model(554, "Yosemite", Industrial, 2, false, false),
+    model(555, "Windsor", Convertible, 2, false, false),
+    model(556, "Monster A", OffRoad, 2, false, false),
+    model(557, "Monster B", OffRoad, 2, false, false),
+    model(558, "Uranus", Sport, 2, false, false),
+    model(559, "Jester", Sport, 2, false, false),
+    model(560, "Sultan", Saloon, 4, false, false),
+    model(561, "Stratum", StationWagon, 4, false, false),
+    model(562, "Elegy", Sport, 2, false, false),
+    model(563, "Raindance", Helicopter, 2, false, false),
+    model(564, "RC Tiger", Rc, 1, false, false),
+    model(565, "Flash", Sport, 2, false, false),
+    model(566, "Tahoma", Lowrider, 4, false, false),
+    model(567, "Savanna", Lowrider, 4, false, false),
+    model(568, "Bandito", OffRoad, 1, false, false),
+    model(569, "Freight Flat", Trailer, 0, false, true),
+    model(570, "Streak Carriage", Trailer, 0, false, true),
+    model(571, "Kart", Unique, 1, false, false),
+    model(572, "Mower", Unique, 1, false, false),
+    model(573, "Dune", OffRoad, 2, false, false),
+    model(574, "Sweeper", Unique, 1, false, false),
+    model(575, "Broadway", Lowrider, 2, false, false),
+    model(576, "Tornado", Lowrider, 2, false, false),
+    model(577, "AT-400", Airplane, 1, false, false),
+    model(578, "DFT-30", Industrial, 2, false, false),
+    model(579, "Huntley", OffRoad, 4, false, false),
+    model(580, "Stafford", Saloon, 4, false, false),
+    model(581, "BF-400", Bike, 2, false, false),
+    model(582, "Newsvan", Industrial, 4, false, false),
+    model(583, "Tug", Unique, 1, false, false),
+    model(584, "Petrol Trailer", Trailer, 0, false, true),
+    model(585, "Emperor", Saloon, 4, false, false),
+    model(586, "Wayfarer", Bike, 2, false, false),
+    model(587, "Euros", Sport, 2, false, false),
+    model(588, "Hotdog", Unique, 2, false, false),
+    model(589, "Club", Sport, 2, false, false),
+    model(590, "Freight Box", Trailer, 0, false, true),
+    model(591, "Article Trailer 3", Trailer, 0, false, true),
+    model(592, "Andromada", Airplane, 1, false, false),
+    model(593, "Dodo", Airplane, 2, false, false),
+    model(594, "RC Cam", Rc, 1, false, false),
+    model(595, "Launch", Boat, 1, false, false),
+    model(596, "Police Car (LSPD)", PublicService, 4, true, false),
+    model(597, "Police Car (SFPD)", PublicService, 4, true, false),
+    model(598, "Police Car (LVPD)", PublicService, 4, true, false),
+    model(599, "Police Ranger", PublicService, 2, true, false),
+    model(600, "Picador", Saloon, 2, false, false),
+    model(601, "S.W.A.T.", PublicService, 1, true, false),
+    model(602, "Alpha", Sport, 2, false, false),
+    model(603, "Phoenix", Sport, 2, false, false),
+    model(604, "Glendale Shit", Saloon, 4, false, false),
+    model(605, "Sadler Shit", Industrial, 2, false, false),
+    model(606, "Baggage Trailer A", Trailer, 0, false, true),
+    model(607, "Baggage Trailer B", Trailer, 0, false, true),
+    model(608, "Tug Stairs Trailer", Trailer, 0, false, true),
+    model(609, "Boxville", Industrial, 4, false, false),
+    model(610, "Farm Trailer", Trailer, 0, false, true),
+    model(611, "Utility Trailer", Trailer, 0, false, true),
+];
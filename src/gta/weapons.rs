@@ -0,0 +1,195 @@
+pub const MAX_WEAPON_ID: u8 = 46;
+
+// Death reasons that aren't weapons.
+pub const REASON_VEHICLE: u8 = 49;
+pub const REASON_HELICOPTER_BLADES: u8 = 50;
+pub const REASON_EXPLOSION: u8 = 51;
+pub const REASON_DROWNED: u8 = 53;
+pub const REASON_COLLISION: u8 = 54;
+pub const REASON_CONNECT: u8 = 200;
+pub const REASON_DISCONNECT: u8 = 201;
+
+#[derive(Debug)]
+pub struct Weapon {
+    pub id: u8,
+    pub name: &'static str,
+    pub slot: Option<u8>,
+    pub model: Option<u16>,
+    pub clip_size: u16,
+    /// Damage of a single hit, all pellets for shotguns.
+    pub damage: f32,
+    pub range: f32,
+    /// Character of the weapon in the kill list font (GTAWEAPON3).
+    pub glyph: Option<char>,
+}
+
+impl Weapon {
+    pub fn is_melee(&self) -> bool {
+        self.slot.map(|slot| slot <= 1 || slot == 10).unwrap_or(false)
+    }
+
+    pub fn is_firearm(&self) -> bool {
+        self.slot.map(|slot| slot >= 2 && slot <= 7).unwrap_or(false)
+    }
+}
+
+const fn weapon(
+    id: u8,
+    name: &'static str,
+    slot: Option<u8>,
+    model: Option<u16>,
+    clip_size: u16,
+    damage: f32,
+    range: f32,
+    glyph: Option<char>,
+) -> Weapon {
+    Weapon {
+        id,
+        name,
+        slot,
+        model,
+        clip_size,
+        damage,
+        range,
+        glyph,
+    }
+}
+
+pub fn weapon_info(weapon: i32) -> Option<&'static Weapon> {
+    if weapon < 0 || weapon > MAX_WEAPON_ID as i32 {
+        return None;
+    }
+
+    WEAPONS.get(weapon as usize)
+}
+
+pub fn weapon_by_model(model: i32) -> Option<&'static Weapon> {
+    WEAPONS
+        .iter()
+        .find(|weapon| weapon.model.map(|id| id as i32) == Some(model))
+}
+
+pub fn find_weapon(name: &str) -> Option<&'static Weapon> {
+    let name = name.to_lowercase();
+
+    WEAPONS
+        .iter()
+        .find(|weapon| weapon.name.to_lowercase() == name)
+        .or_else(|| {
+            WEAPONS
+                .iter()
+                .find(|weapon| weapon.name.to_lowercase().contains(&name))
+        })
+}
+
+pub fn weapons() -> impl Iterator<Item = &'static Weapon> {
+    WEAPONS.iter()
+}
+
+pub fn death_reason_name(reason: u8) -> Option<&'static str> {
+    match reason {
+        REASON_VEHICLE => Some("Vehicle"),
+        REASON_HELICOPTER_BLADES => Some("Helicopter Blades"),
+        REASON_EXPLOSION => Some("Explosion"),
+        REASON_DROWNED => Some("Drowned"),
+        REASON_COLLISION => Some("Collision"),
+        REASON_CONNECT => Some("Connect"),
+        REASON_DISCONNECT => Some("Disconnect"),
+        reason => weapon_info(reason as i32).map(|weapon| weapon.name),
+    }
+}
+
+/// Kill list glyph for any death reason, weapons included.
+pub fn death_reason_glyph(reason: u8) -> Option<char> {
+    match reason {
+        REASON_VEHICLE => Some('L'),
+        REASON_HELICOPTER_BLADES => Some('R'),
+        REASON_EXPLOSION => Some('Q'),
+        REASON_DROWNED => Some('J'),
+        REASON_COLLISION => Some('K'),
+        REASON_CONNECT => Some('N'),
+        REASON_DISCONNECT => Some('N'),
+        reason => weapon_info(reason as i32).and_then(|weapon| weapon.glyph),
+    }
+}
+
+static WEAPONS: [Weapon; 47] = [
+    weapon(0, "Fist", Some(0), None, 0, 1.32, 1.76, Some('%')),
+    weapon(1, "Brass Knuckles", Some(0), Some(331), 0, 1.32, 1.76, Some('B')),
+    weapon(2, "Golf Club", Some(1), Some(333), 0, 1.32, 1.76, Some('>')),
+    weapon(3, "Nightstick", Some(1), Some(334), 0, 1.32, 1.76, Some('(')),
+    weapon(4, "Knife", Some(1), Some(335), 0, 1.32, 1.76, Some('C')),
+    weapon(5, "Baseball Bat", Some(1), Some(336), 0, 1.32, 1.76, Some('?')),
+    weapon(6, "Shovel", Some(1), Some(337), 0, 1.32, 1.76, Some('&')),
+    weapon(7, "Pool Cue", Some(1), Some(338), 0, 1.32, 1.76, Some('"')),
+    weapon(8, "Katana", Some(1), Some(339), 0, 1.32, 1.76, Some('!')),
+    weapon(9, "Chainsaw", Some(1), Some(341), 0, 27.06, 1.76, Some('1')),
+    weapon(10, "Purple Dildo", Some(10), Some(321), 0, 1.32, 1.76, Some('E')),
+    weapon(11, "Dildo", Some(10), Some(322), 0, 1.32, 1.76, Some('E')),
+    weapon(12, "Vibrator", Some(10), Some(323), 0, 1.32, 1.76, Some('E')),
+    weapon(13, "Silver Vibrator", Some(10), Some(324), 0, 1.32, 1.76, Some('E')),
+    weapon(14, "Flowers", Some(10), Some(325), 0, 1.32, 1.76, Some('$')),
+    weapon(15, "Cane", Some(10), Some(326), 0, 1.32, 1.76, Some('#')),
+    weapon(16, "Grenade", Some(8), Some(342), 1, 82.5, 40.0, Some('@')),
+    weapon(17, "Tear Gas", Some(8), Some(343), 1, 0.0, 40.0, Some('D')),
+    weapon(18, "Molotov Cocktail", Some(8), Some(344), 1, 0.0, 40.0, Some('[')),
+    weapon(19, "Unused", None, None, 0, 0.0, 0.0, None),
+    weapon(20, "Unused", None, None, 0, 0.0, 0.0, None),
+    weapon(21, "Unused", None, None, 0, 0.0, 0.0, None),
+    weapon(22, "Colt 45", Some(2), Some(346), 17, 8.25, 35.0, Some('6')),
+    weapon(23, "Silenced 9mm", Some(2), Some(347), 17, 13.2, 35.0, Some('2')),
+    weapon(24, "Desert Eagle", Some(2), Some(348), 7, 46.2, 35.0, Some('3')),
+    weapon(25, "Shotgun", Some(3), Some(349), 1, 49.5, 40.0, Some('=')),
+    weapon(26, "Sawnoff Shotgun", Some(3), Some(350), 2, 49.5, 35.0, Some('0')),
+    weapon(27, "Combat Shotgun", Some(3), Some(351), 7, 39.6, 40.0, Some('+')),
+    weapon(28, "Micro SMG", Some(4), Some(352), 50, 6.6, 35.0, Some('I')),
+    weapon(29, "MP5", Some(4), Some(353), 30, 8.25, 45.0, Some('8')),
+    weapon(30, "AK-47", Some(5), Some(355), 30, 9.9, 70.0, Some('H')),
+    weapon(31, "M4", Some(5), Some(356), 50, 9.9, 90.0, Some('5')),
+    weapon(32, "Tec-9", Some(4), Some(372), 50, 6.6, 35.0, Some('7')),
+    weapon(33, "Country Rifle", Some(6), Some(357), 1, 24.75, 100.0, Some('.')),
+    weapon(34, "Sniper Rifle", Some(6), Some(358), 1, 41.25, 100.0, Some('A')),
+    weapon(35, "RPG", Some(7), Some(359), 1, 82.5, 55.0, Some('4')),
+    weapon(36, "HS Rocket", Some(7), Some(360), 1, 82.5, 55.0, Some(')')),
+    weapon(37, "Flamethrower", Some(7), Some(361), 500, 0.33, 5.1, Some('P')),
+    weapon(38, "Minigun", Some(7), Some(362), 500, 46.2, 75.0, Some('F')),
+    weapon(39, "Satchel Charge", Some(8), Some(363), 1, 82.5, 40.0, Some('<')),
+    weapon(40, "Detonator", Some(12), Some(364), 0, 0.0, 0.0, Some(';')),
+    weapon(41, "Spraycan", Some(9), Some(365), 500, 0.33, 6.1, Some('/')),
+    weapon(42, "Fire Extinguisher", Some(9), Some(366), 500, 0.33, 10.1, Some(',')),
+    weapon(43, "Camera", Some(9), Some(367), 36, 0.0, 100.0, None),
+    weapon(44, "Night Vision Goggles", Some(11), Some(368), 0, 0.0, 0.0, None),
+    weapon(45, "Thermal Goggles", Some(11), Some(369), 0, 0.0, 0.0, None),
+    weapon(46, "Parachute", Some(11), Some(371), 0, 0.0, 0.0, None),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // samp's CDeathWindow::SpriteIDForWeapon
+    const SAMP_GLYPHS: &[(u8, char)] = &[
+        (0, '%'), (1, 'B'), (2, '>'), (3, '('), (4, 'C'), (5, '?'), (6, '&'), (7, '"'),
+        (8, '!'), (9, '1'), (10, 'E'), (11, 'E'), (12, 'E'), (13, 'E'), (14, '$'), (15, '#'),
+        (16, '@'), (17, 'D'), (18, '['), (22, '6'), (23, '2'), (24, '3'), (25, '='),
+        (26, '0'), (27, '+'), (28, 'I'), (29, '8'), (30, 'H'), (31, '5'), (32, '7'),
+        (33, '.'), (34, 'A'), (35, '4'), (36, ')'), (37, 'P'), (38, 'F'), (39, '<'),
+        (40, ';'), (41, '/'), (42, ','), (REASON_VEHICLE, 'L'),
+        (REASON_HELICOPTER_BLADES, 'R'), (REASON_EXPLOSION, 'Q'), (REASON_DROWNED, 'J'),
+        (REASON_COLLISION, 'K'), (REASON_CONNECT, 'N'), (REASON_DISCONNECT, 'N'),
+    ];
+
+    #[test]
+    fn glyphs_match_samp() {
+        for &(reason, glyph) in SAMP_GLYPHS {
+            assert_eq!(death_reason_glyph(reason), Some(glyph), "reason {}", reason);
+        }
+    }
+
+    #[test]
+    fn no_glyph() {
+        for &reason in &[19, 20, 21, 43, 44, 45, 52, 55, 255] {
+            assert_eq!(death_reason_glyph(reason), None, "reason {}", reason);
+        }
+    }
+}
@@ -1,17 +1,36 @@
+pub mod actors;
 pub mod audio_stream;
+pub mod chat;
+pub mod command_args;
+pub mod commands;
+pub mod damage;
 pub mod deathwindow;
+pub mod encoding;
+pub mod gangzones;
 pub mod inputs;
 pub mod label_pool;
+pub mod menus;
 pub mod netgame;
 pub mod objects;
 pub mod packets;
+pub mod pickups;
 pub mod players;
+pub mod query;
+pub mod reconnect;
+pub mod rpc;
+pub mod scoreboard;
 pub mod spawnscreen;
+pub mod stdstring;
+pub mod textdraws;
 pub mod v037;
 pub mod v037r3;
 pub mod v03dlr1;
+pub mod vehicles;
 pub mod version;
 
+use std::sync::OnceLock;
+
+pub use stdstring::{CStdString, OwnedStdString, StringAllocator};
 use version::{version, Version};
 
 pub type TICK = std::os::raw::c_ulong;
@@ -21,38 +40,74 @@ pub type ID = std::os::raw::c_ushort;
 pub type NUMBER = std::os::raw::c_uchar;
 pub type D3DCOLOR = std::os::raw::c_ulong;
 
+impl CStdString {
+    /// Text in the current codepage.
+    pub fn decode(&self) -> String {
+        encoding::decode(self.try_bytes().unwrap_or(&[]))
+    }
+}
+
+impl OwnedStdString {
+    /// Encodes `text` with the current codepage.
+    pub fn from_text(text: &str) -> OwnedStdString {
+        OwnedStdString::new(&encoding::encode(text))
+    }
+}
+
+/// samp's own allocation functions, memory samp frees has to come from them.
 #[derive(Debug, Clone, Copy)]
-#[repr(C)]
-pub struct CStdString {
-    bytes: [u8; 16], // it's like union
-    len: u32,
-    capacity: u32,
+pub struct GameAllocator {
+    alloc: extern "C" fn(usize) -> *mut u8,
+    free: extern "C" fn(*mut u8),
 }
 
-impl CStdString {
-    fn bytes(&self) -> &[u8] {
-        let len = self.len as usize;
+static SAMP_ALLOCATOR: OnceLock<Option<GameAllocator>> = OnceLock::new();
+
+impl GameAllocator {
+    /// `malloc` and `free` of the CRT samp.dll imports `operator new` from, which is where its
+    /// `std::string` buffers come from. Looked up once. `None` unless all three come from the
+    /// same CRT: samp links its CRT statically or imports from several, and then nothing
+    /// outside samp can allocate for it.
+    pub fn samp() -> Option<&'static GameAllocator> {
+        SAMP_ALLOCATOR
+            .get_or_init(|| match version() {
+                Version::V037 | Version::V037R3 | Version::V03DLR1 => unsafe {
+                    GameAllocator::from_imports(handle())
+                },
+                _ => None,
+            })
+            .as_ref()
+    }
+
+    unsafe fn from_imports(module: *const u8) -> Option<GameAllocator> {
+        // operator new(size_t)
+        let new = crate::utils::find_import(module, "??2@YAPAXI@Z")?;
+        let malloc = crate::utils::find_import(module, "malloc")?;
+        let free = crate::utils::find_import(module, "free")?;
 
-        if self.capacity <= 0xF {
-            &self.bytes[0..len]
-        } else {
-            let ptr = unsafe { (&self.bytes as *const _ as *const usize).read() };
-            let ptr = ptr as *const u8;
+        let same_crt = new.dll.eq_ignore_ascii_case(&malloc.dll)
+            && malloc.dll.eq_ignore_ascii_case(&free.dll);
 
-            unsafe { std::slice::from_raw_parts(ptr, len) }
+        if !same_crt || malloc.address.is_null() || free.address.is_null() {
+            return None;
         }
-    }
 
-    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
-        std::str::from_utf8(self.bytes())
+        Some(GameAllocator {
+            alloc: std::mem::transmute(malloc.address),
+            free: std::mem::transmute(free.address),
+        })
     }
+}
 
-    pub fn as_str_unchecked(&self) -> &str {
-        unsafe { std::str::from_utf8_unchecked(self.bytes()) }
+impl StringAllocator for GameAllocator {
+    fn alloc(&self, size: usize) -> *mut u8 {
+        (self.alloc)(size)
     }
 
-    pub fn to_string(&self) -> String {
-        self.as_str_unchecked().to_owned()
+    fn free(&self, ptr: *mut u8) {
+        if !ptr.is_null() {
+            (self.free)(ptr);
+        }
     }
 }
 
@@ -0,0 +1,138 @@
+use super::players::GamePed;
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::matrix::{CVector, RwMatrix};
+use crate::gta::skins::{skin_info, Skin};
+
+pub const MAX_ACTORS: usize = 1000;
+
+pub struct Actor<'a> {
+    id: u16,
+    actor_v1: Option<&'a r1::CActor>,
+    actor_v3: Option<&'a r3::CActor>,
+    actor_dl: Option<&'a dl::CActor>,
+}
+
+impl<'a> Actor<'a> {
+    fn new_v1(id: usize, actor: &'a r1::CActor) -> Actor<'a> {
+        Actor {
+            id: id as u16,
+            actor_v1: Some(actor),
+            actor_v3: None,
+            actor_dl: None,
+        }
+    }
+
+    fn new_v3(id: usize, actor: &'a r3::CActor) -> Actor<'a> {
+        Actor {
+            id: id as u16,
+            actor_v3: Some(actor),
+            actor_v1: None,
+            actor_dl: None,
+        }
+    }
+
+    fn new_dl(id: usize, actor: &'a dl::CActor) -> Actor<'a> {
+        Actor {
+            id: id as u16,
+            actor_dl: Some(actor),
+            actor_v1: None,
+            actor_v3: None,
+        }
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// GTA ped of the actor, `None` while it isn't streamed in.
+    pub fn gta_ped(&self) -> Option<&GamePed> {
+        if let Some(actor) = self.actor_v1.as_ref() {
+            return actor.gta_ped();
+        }
+
+        if let Some(actor) = self.actor_v3.as_ref() {
+            return actor.gta_ped();
+        }
+
+        if let Some(actor) = self.actor_dl.as_ref() {
+            return actor.gta_ped();
+        }
+
+        None
+    }
+
+    pub fn matrix(&self) -> Option<RwMatrix> {
+        self.gta_ped()
+            .filter(|ped| !ped.matrix.is_null())
+            .map(|ped| unsafe { ped.matrix.read() })
+    }
+
+    pub fn position(&self) -> CVector {
+        self.matrix()
+            .map(|matrix| matrix.pos)
+            .unwrap_or_else(|| CVector::zero())
+    }
+
+    /// Facing angle in degrees as `GetActorFacingAngle` gives it.
+    pub fn facing_angle(&self) -> f32 {
+        self.gta_ped()
+            .map(|ped| ped.rotation().to_degrees().rem_euclid(360.0))
+            .unwrap_or(0.0)
+    }
+
+    pub fn health(&self) -> f32 {
+        self.gta_ped().map(|ped| ped.health()).unwrap_or(0.0)
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        if let Some(actor) = self.actor_v1.as_ref() {
+            return actor.m_bInvulnerable;
+        }
+
+        if let Some(actor) = self.actor_v3.as_ref() {
+            return actor.m_bInvulnerable;
+        }
+
+        if let Some(actor) = self.actor_dl.as_ref() {
+            return actor.m_bInvulnerable;
+        }
+
+        false
+    }
+
+    pub fn skin(&self) -> Option<u16> {
+        self.gta_ped().map(|ped| ped.model_index())
+    }
+
+    pub fn skin_info(&self) -> Option<&'static Skin> {
+        self.skin().and_then(|skin| skin_info(skin as i32))
+    }
+
+    pub fn get(actor_id: i32) -> Option<Actor<'a>> {
+        let id = actor_id as usize;
+
+        match version() {
+            Version::V037 => r1::find_actor(actor_id).map(|actor| Actor::new_v1(id, actor)),
+            Version::V037R3 => r3::find_actor(actor_id).map(|actor| Actor::new_v3(id, actor)),
+            Version::V03DLR1 => dl::find_actor(actor_id).map(|actor| Actor::new_dl(id, actor)),
+            _ => None,
+        }
+    }
+}
+
+/// Every actor the server has created, streamed in or not.
+pub fn actors<'a>() -> Vec<Actor<'a>> {
+    let actors = match version() {
+        Version::V037 => r1::actors()
+            .map(|iter| iter.map(|(id, actor)| Actor::new_v1(id, actor)).collect()),
+        Version::V037R3 => r3::actors()
+            .map(|iter| iter.map(|(id, actor)| Actor::new_v3(id, actor)).collect()),
+        Version::V03DLR1 => dl::actors()
+            .map(|iter| iter.map(|(id, actor)| Actor::new_dl(id, actor)).collect()),
+        _ => None,
+    };
+
+    actors.unwrap_or_else(Vec::new)
+}
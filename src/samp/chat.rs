@@ -0,0 +1,523 @@
+use retour::GenericDetour;
+
+use std::os::raw::{c_char, c_int};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::commands::is_command_registered;
+use super::encoding;
+use super::inputs::{Input, MAX_INPUT_LENGTH};
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl, D3DCOLOR};
+use crate::gta::color::{parse_color_tags, Rgba};
+
+pub const MAX_MESSAGES: usize = 100;
+pub const MAX_MESSAGE_LENGTH: usize = 143;
+pub const MAX_PREFIX_LENGTH: usize = 27;
+
+type AddEntryFn =
+    extern "thiscall" fn(*mut (), c_int, *const c_char, *const c_char, D3DCOLOR, D3DCOLOR);
+
+struct AddEntryHook {
+    hook: GenericDetour<AddEntryFn>,
+    callback: Box<dyn FnMut(&ChatLine)>,
+}
+
+static mut ADD_ENTRY_HOOK: Option<AddEntryHook> = None;
+
+struct RateLimit {
+    interval: Duration,
+    last_send: Option<Instant>,
+}
+
+impl RateLimit {
+    /// Takes the send slot at `now`, or tells how long to wait for it.
+    fn claim(&mut self, now: Instant) -> Result<(), SendError> {
+        let elapsed = self.last_send.map(|last| now.saturating_duration_since(last));
+
+        match elapsed {
+            Some(elapsed) if elapsed < self.interval => {
+                Err(SendError::RateLimited(self.interval - elapsed))
+            }
+            _ => {
+                self.last_send = Some(now);
+                Ok(())
+            }
+        }
+    }
+}
+
+// the default server flood check kicks in at about one message per second
+static RATE_LIMIT: Mutex<RateLimit> = Mutex::new(RateLimit {
+    interval: Duration::from_millis(1000),
+    last_send: None,
+});
+
+fn rate_limit() -> std::sync::MutexGuard<'static, RateLimit> {
+    RATE_LIMIT.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+extern "thiscall" fn add_entry_hook(
+    this: *mut (),
+    entry_type: c_int,
+    text: *const c_char,
+    prefix: *const c_char,
+    text_color: D3DCOLOR,
+    prefix_color: D3DCOLOR,
+) {
+    unsafe {
+        if let Some(hook) = ADD_ENTRY_HOOK.as_mut() {
+            hook.hook
+                .call(this, entry_type, text, prefix, text_color, prefix_color);
+
+            let timestamp = newest_timestamp().unwrap_or(0);
+
+            let line = ChatLine::new(
+                EntryType::from(entry_type),
+                timestamp,
+                encoding::decode_c_str(prefix),
+                prefix_color as u32,
+                encoding::decode_c_str(text),
+                text_color as u32,
+            );
+
+            (hook.callback)(&line);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryType {
+    None,
+    /// Player message, has a prefix with the sender name.
+    Chat,
+    /// Server info like "Connected to ...".
+    Info,
+    /// `SendClientMessage` and local messages.
+    Debug,
+}
+
+impl From<i32> for EntryType {
+    fn from(entry_type: i32) -> EntryType {
+        match entry_type {
+            2 => EntryType::Chat,
+            4 => EntryType::Info,
+            8 => EntryType::Debug,
+            _ => EntryType::None,
+        }
+    }
+}
+
+impl From<EntryType> for i32 {
+    fn from(entry_type: EntryType) -> i32 {
+        match entry_type {
+            EntryType::None => 0,
+            EntryType::Chat => 2,
+            EntryType::Info => 4,
+            EntryType::Debug => 8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatSegment {
+    pub color: Rgba,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatLine {
+    pub entry_type: EntryType,
+    /// Unix time the entry was added at.
+    pub timestamp: i32,
+    pub prefix: String,
+    pub prefix_color: Rgba,
+    pub text: String,
+    pub text_color: Rgba,
+    /// `text` split on `{RRGGBB}` tags, the first segment has `text_color` unless tagged.
+    pub segments: Vec<ChatSegment>,
+}
+
+impl ChatLine {
+    pub(super) fn new(
+        entry_type: EntryType,
+        timestamp: i32,
+        prefix: String,
+        prefix_color: u32,
+        text: String,
+        text_color: u32,
+    ) -> ChatLine {
+        let text_color = Rgba::from_argb(text_color);
+
+        let segments = parse_color_tags(&text)
+            .into_iter()
+            .map(|segment| ChatSegment {
+                color: segment.color.unwrap_or(text_color),
+                text: segment.text.to_owned(),
+            })
+            .collect();
+
+        ChatLine {
+            entry_type,
+            timestamp,
+            prefix,
+            prefix_color: Rgba::from_argb(prefix_color),
+            text,
+            text_color,
+            segments,
+        }
+    }
+
+    /// Text without color tags.
+    pub fn plain_text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect()
+    }
+}
+
+/// Time samp stamped its newest entry with, samp adds entries at the end of the buffer.
+fn newest_timestamp() -> Option<i32> {
+    let newest = MAX_MESSAGES - 1;
+
+    match version() {
+        Version::V037 => r1::chat().map(|chat| chat.m_entry[newest].m_timestamp as i32),
+        Version::V037R3 => r3::chat().map(|chat| chat.m_entry[newest].m_timestamp as i32),
+        Version::V03DLR1 => dl::chat().map(|chat| chat.m_entry[newest].m_timestamp as i32),
+        _ => None,
+    }
+}
+
+/// The whole history buffer, oldest line first.
+pub fn lines() -> Vec<ChatLine> {
+    let lines = match version() {
+        Version::V037 => r1::chat().map(|chat| {
+            chat.m_entry
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        Version::V037R3 => r3::chat().map(|chat| {
+            chat.m_entry
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        Version::V03DLR1 => dl::chat().map(|chat| {
+            chat.m_entry
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        _ => None,
+    };
+
+    lines.unwrap_or_else(Vec::new)
+}
+
+fn page() -> Option<(usize, usize)> {
+    let (page_size, scroll) = match version() {
+        Version::V037 => r1::chat().map(|chat| (chat.m_nPageSize, chat.m_nScrollbarPos))?,
+        Version::V037R3 => r3::chat().map(|chat| (chat.m_nPageSize, chat.m_nScrollbarPos))?,
+        Version::V03DLR1 => dl::chat().map(|chat| (chat.m_nPageSize, chat.m_nScrollbarPos))?,
+        _ => return None,
+    };
+
+    let page_size = (page_size as usize).min(MAX_MESSAGES);
+    let scroll = (scroll.max(0) as usize).min(MAX_MESSAGES - page_size);
+
+    Some((scroll, page_size))
+}
+
+/// Lines currently shown in the chat window, taking the scroll position into account.
+pub fn visible_lines() -> Vec<ChatLine> {
+    let (scroll, page_size) = match page() {
+        Some(page) => page,
+        None => return Vec::new(),
+    };
+
+    let lines = match version() {
+        Version::V037 => r1::chat().map(|chat| {
+            chat.m_entry[scroll..scroll + page_size]
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        Version::V037R3 => r3::chat().map(|chat| {
+            chat.m_entry[scroll..scroll + page_size]
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        Version::V03DLR1 => dl::chat().map(|chat| {
+            chat.m_entry[scroll..scroll + page_size]
+                .iter()
+                .filter_map(|entry| entry.line())
+                .collect()
+        }),
+
+        _ => None,
+    };
+
+    lines.unwrap_or_else(Vec::new)
+}
+
+pub fn last_line() -> Option<ChatLine> {
+    lines().pop()
+}
+
+pub fn timestamps_enabled() -> bool {
+    match version() {
+        Version::V037 => r1::chat().map(|chat| chat.m_bTimestamps),
+        Version::V037R3 => r3::chat().map(|chat| chat.m_bTimestamps),
+        Version::V03DLR1 => dl::chat().map(|chat| chat.m_bTimestamps),
+        _ => None,
+    }
+    .unwrap_or(false)
+}
+
+fn add_entry(entry_type: EntryType, text: &str, prefix: Option<&str>, colors: (Rgba, Rgba)) {
+    let address = match version() {
+        Version::V037 => r1::CCHAT_ADDENTRY,
+        Version::V037R3 => r3::CCHAT_ADDENTRY,
+        Version::V03DLR1 => dl::CCHAT_ADDENTRY,
+        _ => return,
+    };
+
+    let chat = match version() {
+        Version::V037 => r1::chat().map(|chat| chat as *mut r1::CChat as *mut ()),
+        Version::V037R3 => r3::chat().map(|chat| chat as *mut r3::CChat as *mut ()),
+        Version::V03DLR1 => dl::chat().map(|chat| chat as *mut dl::CChat as *mut ()),
+        _ => None,
+    };
+
+    let chat = match chat {
+        Some(chat) => chat,
+        None => return,
+    };
+
+    let text = encoding::encode_c_string(text);
+    let prefix = prefix.map(encoding::encode_c_string);
+    let prefix_ptr = prefix
+        .as_ref()
+        .map(|prefix| prefix.as_ptr() as *const c_char)
+        .unwrap_or(std::ptr::null());
+
+    let (text_color, prefix_color) = colors;
+    let text_color = text_color.to_argb() as D3DCOLOR;
+    let prefix_color = prefix_color.to_argb() as D3DCOLOR;
+    let text_ptr = text.as_ptr() as *const c_char;
+    let entry_type = i32::from(entry_type);
+
+    unsafe {
+        // go through the trampoline when hooked so local messages don't show up as incoming ones
+        if let Some(hook) = ADD_ENTRY_HOOK.as_ref() {
+            hook.hook
+                .call(chat, entry_type, text_ptr, prefix_ptr, text_color, prefix_color);
+        } else {
+            let func: AddEntryFn = std::mem::transmute(super::handle().add(address));
+            func(chat, entry_type, text_ptr, prefix_ptr, text_color, prefix_color);
+        }
+    }
+}
+
+/// Adds a local message like `AddChatMessage` in cleo/sampfuncs. `{RRGGBB}` tags are kept.
+pub fn add_message(color: Rgba, text: &str) {
+    add_entry(EntryType::Debug, text, None, (color, color));
+}
+
+/// Adds a player-like line: `prefix` drawn in its own color followed by the text.
+pub fn add_chat_message(prefix: &str, prefix_color: Rgba, text: &str, text_color: Rgba) {
+    add_entry(
+        EntryType::Chat,
+        text,
+        Some(prefix),
+        (text_color, prefix_color),
+    );
+}
+
+/// Calls `callback` for every line samp adds to the chat, except the ones added by this crate.
+pub fn on_message<F: FnMut(&ChatLine) + 'static>(callback: F) {
+    let address = match version() {
+        Version::V037 => r1::CCHAT_ADDENTRY,
+        Version::V037R3 => r3::CCHAT_ADDENTRY,
+        Version::V03DLR1 => dl::CCHAT_ADDENTRY,
+        _ => return,
+    };
+
+    unsafe {
+        let ptr = super::handle().add(address);
+        let func: AddEntryFn = std::mem::transmute(ptr);
+
+        if let Ok(hook) = GenericDetour::new(func, add_entry_hook) {
+            let _ = hook.enable();
+
+            ADD_ENTRY_HOOK = Some(AddEntryHook {
+                hook,
+                callback: Box::new(callback),
+            });
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendError {
+    NotConnected,
+    Empty,
+    TooLong,
+    /// Sent too fast, the value is how long to wait.
+    RateLimited(Duration),
+}
+
+impl std::fmt::Display for SendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SendError::NotConnected => f.write_str("not connected"),
+            SendError::Empty => f.write_str("empty message"),
+            SendError::TooLong => write!(f, "message is longer than {}", MAX_INPUT_LENGTH),
+            SendError::RateLimited(wait) => write!(f, "rate limited for {:?}", wait),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+/// Minimal time between two messages sent to the server with `send_chat`/`send_command`.
+pub fn set_send_interval(interval: Duration) {
+    rate_limit().interval = interval;
+}
+
+fn encode_input(text: &str) -> Result<Vec<u8>, SendError> {
+    let text = text.trim_end();
+
+    if text.is_empty() {
+        return Err(SendError::Empty);
+    }
+
+    let bytes = encoding::encode_c_string(text);
+
+    if bytes.len() - 1 > MAX_INPUT_LENGTH {
+        return Err(SendError::TooLong);
+    }
+
+    Ok(bytes)
+}
+
+/// Says `text` in the chat as the local player, the text isn't treated as a command.
+pub fn send_chat(text: &str) -> Result<(), SendError> {
+    let text = encode_input(text)?;
+
+    let address = match version() {
+        Version::V037 => r1::CLOCALPLAYER_CHAT,
+        Version::V037R3 => r3::CLOCALPLAYER_CHAT,
+        Version::V03DLR1 => dl::CLOCALPLAYER_CHAT,
+        _ => return Err(SendError::NotConnected),
+    };
+
+    let local_player = match version() {
+        Version::V037 => r1::local_player().map(|player| player as *mut _ as *mut ()),
+        Version::V037R3 => r3::local_player().map(|player| player as *mut _ as *mut ()),
+        Version::V03DLR1 => dl::local_player().map(|player| player as *mut _ as *mut ()),
+        _ => None,
+    };
+
+    let local_player = local_player.ok_or(SendError::NotConnected)?;
+
+    if super::gamestate() != super::Gamestate::Connected {
+        return Err(SendError::NotConnected);
+    }
+
+    rate_limit().claim(Instant::now())?;
+
+    unsafe {
+        let chat: extern "thiscall" fn(*mut (), *const c_char) =
+            std::mem::transmute(super::handle().add(address));
+
+        chat(local_player, text.as_ptr() as *const c_char);
+    }
+
+    Ok(())
+}
+
+/// Runs a command the same way the chat input does: client commands are called
+/// directly (and aren't rate limited), the others are sent to the server.
+pub fn send_command(command: &str) -> Result<(), SendError> {
+    let command = command.trim_start();
+    let command = if command.starts_with('/') {
+        command.to_owned()
+    } else {
+        format!("/{}", command)
+    };
+
+    let text = encode_input(&command)?;
+
+    let address = match version() {
+        Version::V037 => r1::CINPUT_SEND,
+        Version::V037R3 => r3::CINPUT_SEND,
+        Version::V03DLR1 => dl::CINPUT_SEND,
+        _ => return Err(SendError::NotConnected),
+    };
+
+    let name = command[1..].split_whitespace().next().unwrap_or("");
+    let is_local = is_command_registered(name);
+
+    if !is_local {
+        if super::gamestate() != super::Gamestate::Connected {
+            return Err(SendError::NotConnected);
+        }
+
+    }
+
+    let input = Input::get().ok_or(SendError::NotConnected)?;
+
+    if !is_local {
+        rate_limit().claim(Instant::now())?;
+    }
+
+    unsafe {
+        let send: extern "thiscall" fn(*mut Input, *const c_char) =
+            std::mem::transmute(super::handle().add(address));
+
+        send(input, text.as_ptr() as *const c_char);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_claims() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let mut limit = RateLimit {
+            interval: Duration::from_millis(1000),
+            last_send: None,
+        };
+
+        let cases = [
+            (0, Ok(())),
+            (1, Err(SendError::RateLimited(Duration::from_millis(999)))),
+            (600, Err(SendError::RateLimited(Duration::from_millis(400)))),
+            (1000, Ok(())),
+            (1999, Err(SendError::RateLimited(Duration::from_millis(1)))),
+            (2500, Ok(())),
+        ];
+
+        for (millis, expected) in cases {
+            assert_eq!(limit.claim(at(millis)), expected, "at {}ms", millis);
+        }
+
+        limit.interval = Duration::ZERO;
+        assert_eq!(limit.claim(at(2500)), Ok(()));
+    }
+}
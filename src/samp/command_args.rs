@@ -0,0 +1,595 @@
+// Declarative parser for the argument string client commands receive.
+// Pure: players are resolved against a list passed by the caller,
+// `players::player_names()` gives one taken from the player pool.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Player id or a part of a nickname.
+    Player,
+    Integer,
+    Float,
+    /// A single word or a `"quoted string"`.
+    String,
+    /// Everything left in the line, as is.
+    Rest,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Player(u16),
+    Integer(i32),
+    Float(f32),
+    String(String),
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Value {
+        Value::Integer(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Value {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::String(value)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Player(id) => write!(f, "{}", id),
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::String(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgError {
+    Missing {
+        name: String,
+    },
+    InvalidInteger {
+        name: String,
+        value: String,
+    },
+    InvalidFloat {
+        name: String,
+        value: String,
+    },
+    PlayerNotFound {
+        name: String,
+        value: String,
+    },
+    AmbiguousPlayer {
+        name: String,
+        value: String,
+        matches: Vec<(u16, String)>,
+    },
+    UnclosedQuote {
+        name: String,
+    },
+    TooManyArguments {
+        extra: String,
+    },
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgError::Missing { name } => write!(f, "missing <{}>", name),
+            ArgError::InvalidInteger { name, value } => {
+                write!(f, "<{}> must be a whole number, got \"{}\"", name, value)
+            }
+            ArgError::InvalidFloat { name, value } => {
+                write!(f, "<{}> must be a number, got \"{}\"", name, value)
+            }
+            ArgError::PlayerNotFound { value, .. } => {
+                write!(f, "no player matches \"{}\"", value)
+            }
+            ArgError::AmbiguousPlayer { value, matches, .. } => {
+                let matches: Vec<String> = matches
+                    .iter()
+                    .map(|(id, name)| format!("{} ({})", name, id))
+                    .collect();
+
+                write!(f, "\"{}\" matches {}", value, matches.join(", "))
+            }
+            ArgError::UnclosedQuote { name } => write!(f, "unclosed quote in <{}>", name),
+            ArgError::TooManyArguments { extra } => write!(f, "unexpected \"{}\"", extra),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+#[derive(Debug, Clone)]
+struct Param {
+    name: String,
+    kind: ArgKind,
+    optional: bool,
+    default: Option<Value>,
+}
+
+/// Argument list of a command, built once and used on every call:
+///
+/// ```ignore
+/// let args = CommandArgs::new("pay").player("player").integer("amount").default(1);
+/// let parsed = args.parse(input, &players::player_names())?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandArgs {
+    command: String,
+    params: Vec<Param>,
+}
+
+impl CommandArgs {
+    pub fn new(command: &str) -> CommandArgs {
+        CommandArgs {
+            command: command.trim_start_matches('/').to_owned(),
+            params: Vec::new(),
+        }
+    }
+
+    fn param(mut self, name: &str, kind: ArgKind) -> CommandArgs {
+        self.params.push(Param {
+            name: name.to_owned(),
+            kind,
+            optional: false,
+            default: None,
+        });
+
+        self
+    }
+
+    pub fn player(self, name: &str) -> CommandArgs {
+        self.param(name, ArgKind::Player)
+    }
+
+    pub fn integer(self, name: &str) -> CommandArgs {
+        self.param(name, ArgKind::Integer)
+    }
+
+    pub fn float(self, name: &str) -> CommandArgs {
+        self.param(name, ArgKind::Float)
+    }
+
+    pub fn string(self, name: &str) -> CommandArgs {
+        self.param(name, ArgKind::String)
+    }
+
+    /// Takes the rest of the line, so it should be the last argument.
+    pub fn rest(self, name: &str) -> CommandArgs {
+        self.param(name, ArgKind::Rest)
+    }
+
+    /// Makes the last added argument optional.
+    pub fn optional(mut self) -> CommandArgs {
+        if let Some(param) = self.params.last_mut() {
+            param.optional = true;
+        }
+
+        self
+    }
+
+    /// Makes the last added argument optional with a value used when it's omitted.
+    pub fn default<V: Into<Value>>(mut self, value: V) -> CommandArgs {
+        if let Some(param) = self.params.last_mut() {
+            param.optional = true;
+            param.default = Some(value.into());
+        }
+
+        self
+    }
+
+    /// `/pay <player> [amount=1]`
+    pub fn usage(&self) -> String {
+        let mut usage = format!("/{}", self.command);
+
+        for param in &self.params {
+            let name = match param.kind {
+                ArgKind::Rest => format!("{}...", param.name),
+                _ => param.name.clone(),
+            };
+
+            let param = match (param.optional, param.default.as_ref()) {
+                (true, Some(default)) => format!("[{}={}]", name, default),
+                (true, None) => format!("[{}]", name),
+                (false, _) => format!("<{}>", name),
+            };
+
+            usage.push(' ');
+            usage.push_str(&param);
+        }
+
+        usage
+    }
+
+    /// Error text ready to be shown in the chat.
+    pub fn error_message(&self, error: &ArgError) -> String {
+        format!("Error: {}. Usage: {}", error, self.usage())
+    }
+
+    pub fn parse(&self, input: &str, players: &[(u16, String)]) -> Result<ParsedArgs, ArgError> {
+        let mut rest = input.trim();
+        let mut values = Vec::with_capacity(self.params.len());
+
+        for param in &self.params {
+            if rest.is_empty() {
+                if !param.optional {
+                    return Err(ArgError::Missing {
+                        name: param.name.clone(),
+                    });
+                }
+
+                values.push((param.name.clone(), param.default.clone()));
+                continue;
+            }
+
+            let (token, tail) = if param.kind == ArgKind::Rest {
+                (rest.to_owned(), "")
+            } else {
+                next_token(rest).ok_or_else(|| ArgError::UnclosedQuote {
+                    name: param.name.clone(),
+                })?
+            };
+
+            let value = parse_value(param, &token, players)?;
+
+            values.push((param.name.clone(), Some(value)));
+            rest = tail.trim_start();
+        }
+
+        if !rest.is_empty() {
+            return Err(ArgError::TooManyArguments {
+                extra: rest.to_owned(),
+            });
+        }
+
+        Ok(ParsedArgs { values })
+    }
+}
+
+/// Splits off the first word or quoted string, `\"` and `\\` are unescaped inside quotes.
+/// Returns `None` when a quote isn't closed.
+fn next_token(input: &str) -> Option<(String, &str)> {
+    let input = input.trim_start();
+
+    if let Some(quoted) = input.strip_prefix('"') {
+        let mut token = String::new();
+        let mut chars = quoted.char_indices();
+
+        while let Some((index, ch)) = chars.next() {
+            match ch {
+                '"' => return Some((token, &quoted[index + 1..])),
+                '\\' => match chars.next() {
+                    Some((_, escaped @ '"')) | Some((_, escaped @ '\\')) => token.push(escaped),
+                    Some((_, other)) => {
+                        token.push('\\');
+                        token.push(other);
+                    }
+                    None => token.push('\\'),
+                },
+                _ => token.push(ch),
+            }
+        }
+
+        return None;
+    }
+
+    let end = input.find(char::is_whitespace).unwrap_or(input.len());
+    Some((input[..end].to_owned(), &input[end..]))
+}
+
+fn parse_value(param: &Param, token: &str, players: &[(u16, String)]) -> Result<Value, ArgError> {
+    let name = || param.name.clone();
+
+    match param.kind {
+        ArgKind::Integer => token
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| ArgError::InvalidInteger {
+                name: name(),
+                value: token.to_owned(),
+            }),
+
+        ArgKind::Float => token
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::Float)
+            .ok_or_else(|| ArgError::InvalidFloat {
+                name: name(),
+                value: token.to_owned(),
+            }),
+
+        ArgKind::Player => resolve_player(token, players).map(Value::Player).map_err(|matches| {
+            if matches.is_empty() {
+                ArgError::PlayerNotFound {
+                    name: name(),
+                    value: token.to_owned(),
+                }
+            } else {
+                ArgError::AmbiguousPlayer {
+                    name: name(),
+                    value: token.to_owned(),
+                    matches,
+                }
+            }
+        }),
+
+        ArgKind::String | ArgKind::Rest => Ok(Value::String(token.to_owned())),
+    }
+}
+
+/// Finds a player by id, exact nickname, nickname prefix or any part of the nickname,
+/// all case insensitive. On failure returns every candidate of the first ambiguous step
+/// (empty if nothing matched at all).
+pub fn resolve_player(query: &str, players: &[(u16, String)]) -> Result<u16, Vec<(u16, String)>> {
+    if let Ok(id) = query.parse::<u16>() {
+        return players
+            .iter()
+            .find(|(player_id, _)| *player_id == id)
+            .map(|(id, _)| *id)
+            .ok_or_else(Vec::new);
+    }
+
+    let query = query.to_lowercase();
+
+    let steps: [&dyn Fn(&str) -> bool; 3] = [
+        &|name| name == query,
+        &|name| name.starts_with(&query),
+        &|name| name.contains(&query),
+    ];
+
+    for matches_query in steps.iter() {
+        let matches: Vec<(u16, String)> = players
+            .iter()
+            .filter(|(_, name)| matches_query(&name.to_lowercase()))
+            .cloned()
+            .collect();
+
+        match matches.len() {
+            0 => continue,
+            1 => return Ok(matches[0].0),
+            _ => return Err(matches),
+        }
+    }
+
+    Err(Vec::new())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedArgs {
+    values: Vec<(String, Option<Value>)>,
+}
+
+impl ParsedArgs {
+    /// `None` for unknown names and omitted optional arguments without a default.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(param, _)| param == name)
+            .and_then(|(_, value)| value.as_ref())
+    }
+
+    pub fn player(&self, name: &str) -> Option<u16> {
+        match self.get(name) {
+            Some(Value::Player(id)) => Some(*id),
+            _ => None,
+        }
+    }
+
+    pub fn integer(&self, name: &str) -> Option<i32> {
+        match self.get(name) {
+            Some(Value::Integer(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Integers are accepted as well, so `[x=0]` defaults don't need to be written as floats.
+    pub fn float(&self, name: &str) -> Option<f32> {
+        match self.get(name) {
+            Some(Value::Float(value)) => Some(*value),
+            Some(Value::Integer(value)) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(Value::String(value)) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn players() -> Vec<(u16, String)> {
+        vec![
+            (0, "John_Smith".to_owned()),
+            (1, "Johnny".to_owned()),
+            (7, "Mike_Jones".to_owned()),
+            (12, "jon".to_owned()),
+            (42, "Carl_Johnson".to_owned()),
+        ]
+    }
+
+    #[test]
+    fn tokens() {
+        let cases: &[(&str, Option<(&str, &str)>)] = &[
+            ("word rest", Some(("word", " rest"))),
+            ("   padded\tnext", Some(("padded", "\tnext"))),
+            ("single", Some(("single", ""))),
+            ("\"two words\" tail", Some(("two words", " tail"))),
+            ("\"say \\\"hi\\\"\"", Some(("say \"hi\"", ""))),
+            ("\"back\\\\slash\"", Some(("back\\slash", ""))),
+            ("\"keep \\n\"", Some(("keep \\n", ""))),
+            ("\"\" empty", Some(("", " empty"))),
+            ("\"unclosed", None),
+        ];
+
+        for &(input, expected) in cases {
+            let token = next_token(input);
+            let token = token.as_ref().map(|(token, tail)| (token.as_str(), *tail));
+
+            assert_eq!(token, expected, "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn whitespace_and_quotes() {
+        let args = CommandArgs::new("/msg").string("title").rest("text");
+        let parsed = args.parse("  \"hello world\"   how  are you  ", &[]).unwrap();
+
+        assert_eq!(parsed.string("title"), Some("hello world"));
+        assert_eq!(parsed.string("text"), Some("how  are you"));
+
+        let error = args.parse("\"hello", &[]).unwrap_err();
+        assert_eq!(error, ArgError::UnclosedQuote { name: "title".to_owned() });
+    }
+
+    #[test]
+    fn optional_and_default() {
+        let args = CommandArgs::new("pay")
+            .player("player")
+            .integer("amount")
+            .default(1)
+            .string("reason")
+            .optional();
+
+        assert_eq!(args.usage(), "/pay <player> [amount=1] [reason]");
+
+        let parsed = args.parse("7", &players()).unwrap();
+        assert_eq!(parsed.player("player"), Some(7));
+        assert_eq!(parsed.integer("amount"), Some(1));
+        assert_eq!(parsed.string("reason"), None);
+        assert_eq!(parsed.get("unknown"), None);
+
+        let parsed = args.parse("7 500 gift", &players()).unwrap();
+        assert_eq!(parsed.integer("amount"), Some(500));
+        assert_eq!(parsed.string("reason"), Some("gift"));
+
+        assert_eq!(
+            args.parse("", &players()).unwrap_err(),
+            ArgError::Missing { name: "player".to_owned() }
+        );
+
+        assert_eq!(
+            args.parse("7 500 gift extra", &players()).unwrap_err(),
+            ArgError::TooManyArguments { extra: "extra".to_owned() }
+        );
+    }
+
+    #[test]
+    fn rest_of_line() {
+        let args = CommandArgs::new("me").rest("action").optional();
+
+        assert_eq!(args.usage(), "/me [action...]");
+        assert_eq!(args.parse("", &[]).unwrap().string("action"), None);
+        assert_eq!(
+            args.parse(" waves \"at\" everyone ", &[]).unwrap().string("action"),
+            Some("waves \"at\" everyone")
+        );
+    }
+
+    #[test]
+    fn number_errors() {
+        let args = CommandArgs::new("pos").float("x").float("y").default(0).integer("world");
+
+        let parsed = args.parse("1.5 -2 3", &[]).unwrap();
+        assert_eq!(parsed.float("x"), Some(1.5));
+        assert_eq!(parsed.float("y"), Some(-2.0));
+        assert_eq!(parsed.integer("world"), Some(3));
+
+        let cases: &[(&str, ArgError, &str)] = &[
+            (
+                "abc",
+                ArgError::InvalidFloat { name: "x".to_owned(), value: "abc".to_owned() },
+                "<x> must be a number, got \"abc\"",
+            ),
+            (
+                "inf",
+                ArgError::InvalidFloat { name: "x".to_owned(), value: "inf".to_owned() },
+                "<x> must be a number, got \"inf\"",
+            ),
+            (
+                "1 2 3.5",
+                ArgError::InvalidInteger { name: "world".to_owned(), value: "3.5".to_owned() },
+                "<world> must be a whole number, got \"3.5\"",
+            ),
+            (
+                "1 2 99999999999",
+                ArgError::InvalidInteger {
+                    name: "world".to_owned(),
+                    value: "99999999999".to_owned(),
+                },
+                "<world> must be a whole number, got \"99999999999\"",
+            ),
+        ];
+
+        for (input, error, message) in cases {
+            assert_eq!(&args.parse(input, &[]).unwrap_err(), error, "input {:?}", input);
+            assert_eq!(error.to_string(), *message);
+        }
+
+        assert_eq!(
+            args.error_message(&cases[0].1),
+            "Error: <x> must be a number, got \"abc\". Usage: /pos <x> [y=0] <world>"
+        );
+    }
+
+    #[test]
+    fn players_resolve() {
+        let players = players();
+        let matches = |ids: &[u16]| -> Vec<(u16, String)> {
+            players.iter().filter(|(id, _)| ids.contains(id)).cloned().collect()
+        };
+
+        let cases: Vec<(&str, Result<u16, Vec<(u16, String)>>)> = vec![
+            ("42", Ok(42)),
+            ("0", Ok(0)),
+            ("5", Err(Vec::new())),
+            ("JON", Ok(12)),
+            ("johnny", Ok(1)),
+            ("mike", Ok(7)),
+            ("carl", Ok(42)),
+            ("john", Err(matches(&[0, 1]))),
+            ("jones", Ok(7)),
+            ("nobody", Err(Vec::new())),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(resolve_player(query, &players), expected, "query {:?}", query);
+        }
+    }
+
+    #[test]
+    fn player_errors() {
+        let args = CommandArgs::new("goto").player("player");
+
+        let error = args.parse("nobody", &players()).unwrap_err();
+        assert_eq!(error.to_string(), "no player matches \"nobody\"");
+
+        let error = args.parse("john", &players()).unwrap_err();
+        assert_eq!(error.to_string(), "\"john\" matches John_Smith (0), Johnny (1)");
+    }
+}
@@ -0,0 +1,232 @@
+use std::os::raw::c_char;
+
+use super::encoding;
+use super::inputs::{Input, CMDPROC, MAX_CLIENT_CMDS, MAX_CMD_LENGTH};
+use super::version::{version, Version};
+
+/// How many Rust commands can be registered at the same time.
+pub const MAX_COMMANDS: usize = 32;
+
+type AddCommandFn = extern "thiscall" fn(*mut Input, *const c_char, CMDPROC);
+type Trampoline = unsafe extern "C" fn(*const c_char);
+
+struct Command {
+    name: String,
+    // taken out while running so the callback can (un)register commands itself
+    callback: Option<Box<dyn FnMut(&str)>>,
+}
+
+const NO_COMMAND: Option<Command> = None;
+
+static mut COMMANDS: [Option<Command>; MAX_COMMANDS] = [NO_COMMAND; MAX_COMMANDS];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandError {
+    /// samp.dll isn't loaded or the input isn't created yet.
+    NotLoaded,
+    InvalidName,
+    AlreadyExists,
+    /// Every trampoline from the pool is taken.
+    NoFreeSlot,
+    /// samp's command table is full.
+    TableFull,
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            CommandError::NotLoaded => "samp input isn't initialized",
+            CommandError::InvalidName => "invalid command name",
+            CommandError::AlreadyExists => "command already exists",
+            CommandError::NoFreeSlot => "too many registered commands",
+            CommandError::TableFull => "samp command table is full",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+// samp calls command handlers without any context, so every slot gets its own function.
+macro_rules! trampolines {
+    ($($slot:literal)*) => {
+        [$({
+            unsafe extern "C" fn trampoline(args: *const c_char) {
+                dispatch($slot, args);
+            }
+
+            trampoline as Trampoline
+        }),*]
+    };
+}
+
+static TRAMPOLINES: [Trampoline; MAX_COMMANDS] = trampolines!(
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+);
+
+fn dispatch(slot: usize, args: *const c_char) {
+    let args = encoding::decode_c_str(args);
+
+    unsafe {
+        let callback = COMMANDS[slot]
+            .as_mut()
+            .and_then(|command| command.callback.take());
+
+        if let Some(mut callback) = callback {
+            callback(args.trim_start());
+
+            // put it back unless the command was removed or replaced meanwhile
+            if let Some(command) = COMMANDS[slot].as_mut() {
+                if command.callback.is_none() {
+                    command.callback = Some(callback);
+                }
+            }
+        }
+    }
+}
+
+fn normalize_name(name: &str) -> Option<&str> {
+    let name = name.strip_prefix('/').unwrap_or(name);
+
+    let is_valid = !name.is_empty()
+        && name.len() <= MAX_CMD_LENGTH
+        && name.bytes().all(|byte| byte.is_ascii_graphic());
+
+    if is_valid {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+fn command_count(input: &Input) -> usize {
+    (input.m_nCommandCount.max(0) as usize).min(MAX_CLIENT_CMDS)
+}
+
+fn command_name(input: &Input, index: usize) -> String {
+    encoding::decode_c_buffer(&input.m_szCommandName[index])
+}
+
+fn find_command(input: &Input, name: &str) -> Option<usize> {
+    (0..command_count(input)).find(|&index| command_name(input, index).eq_ignore_ascii_case(name))
+}
+
+/// Names of every command in samp's table, including the built-in and cleo/sampfuncs ones.
+pub fn commands() -> Vec<String> {
+    Input::get()
+        .map(|input| {
+            (0..command_count(input))
+                .map(|index| command_name(input, index))
+                .collect()
+        })
+        .unwrap_or_else(Vec::new)
+}
+
+pub fn is_command_registered(name: &str) -> bool {
+    let name = name.strip_prefix('/').unwrap_or(name);
+
+    Input::get()
+        .and_then(|input| find_command(input, name))
+        .is_some()
+}
+
+/// Adds `/name` to samp's command table, `callback` gets everything after the command name.
+pub fn register_command<F: FnMut(&str) + 'static>(
+    name: &str,
+    callback: F,
+) -> Result<(), CommandError> {
+    let name = normalize_name(name).ok_or(CommandError::InvalidName)?;
+
+    let address = match version() {
+        Version::V037 => super::v037::CINPUT_ADDCOMMAND,
+        Version::V037R3 => super::v037r3::CINPUT_ADDCOMMAND,
+        Version::V03DLR1 => super::v03dlr1::CINPUT_ADDCOMMAND,
+        _ => return Err(CommandError::NotLoaded),
+    };
+
+    let input = Input::get().ok_or(CommandError::NotLoaded)?;
+
+    if find_command(input, name).is_some() {
+        return Err(CommandError::AlreadyExists);
+    }
+
+    if command_count(input) >= MAX_CLIENT_CMDS - 1 {
+        return Err(CommandError::TableFull);
+    }
+
+    let slot = unsafe {
+        (0..MAX_COMMANDS)
+            .find(|&slot| COMMANDS[slot].is_none())
+            .ok_or(CommandError::NoFreeSlot)?
+    };
+
+    let c_name = encoding::encode_c_string(name);
+    let count = command_count(input);
+
+    unsafe {
+        let add_command: AddCommandFn = std::mem::transmute(super::handle().add(address));
+        add_command(input, c_name.as_ptr() as *const c_char, Some(TRAMPOLINES[slot]));
+    }
+
+    if command_count(input) == count {
+        return Err(CommandError::TableFull);
+    }
+
+    unsafe {
+        COMMANDS[slot] = Some(Command {
+            name: name.to_owned(),
+            callback: Some(Box::new(callback)),
+        });
+    }
+
+    Ok(())
+}
+
+/// Removes a command added with `register_command`. Returns `false` for unknown
+/// names and for commands that belong to samp or other plugins.
+pub fn unregister_command(name: &str) -> bool {
+    let name = name.strip_prefix('/').unwrap_or(name);
+
+    let slot = unsafe {
+        COMMANDS.iter().position(|command| {
+            command
+                .as_ref()
+                .map(|command| command.name.eq_ignore_ascii_case(name))
+                .unwrap_or(false)
+        })
+    };
+
+    let slot = match slot {
+        Some(slot) => slot,
+        None => return false,
+    };
+
+    if let Some(input) = Input::get() {
+        let count = command_count(input);
+        let trampoline = TRAMPOLINES[slot] as usize;
+        let mut procs = input.m_pCommandProc;
+
+        let index = procs[..count]
+            .iter()
+            .position(|proc| proc.map(|proc| proc as usize) == Some(trampoline));
+
+        if let Some(index) = index {
+            for i in index..count - 1 {
+                procs[i] = procs[i + 1];
+                input.m_szCommandName[i] = input.m_szCommandName[i + 1];
+            }
+
+            procs[count - 1] = None;
+            input.m_szCommandName[count - 1] = [0; MAX_CMD_LENGTH + 1];
+            input.m_pCommandProc = procs;
+            input.m_nCommandCount = (count - 1) as i32;
+        }
+    }
+
+    unsafe {
+        COMMANDS[slot] = None;
+    }
+
+    true
+}
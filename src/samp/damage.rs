@@ -0,0 +1,403 @@
+// Damage status bitfields as SA-MP sends them in UpdateVehicleDamageStatus
+// and stores them in the vehicle pool (see GetVehicleDamageStatus on the wiki).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelState {
+    Undamaged,
+    Damaged,
+    Hanging,
+    Removed,
+    /// Nibble values GTA doesn't use, kept so the raw status survives a round trip.
+    Other(u8),
+}
+
+impl Default for PanelState {
+    fn default() -> PanelState {
+        PanelState::Undamaged
+    }
+}
+
+impl From<u8> for PanelState {
+    fn from(state: u8) -> PanelState {
+        match state & 0xF {
+            0 => PanelState::Undamaged,
+            1 => PanelState::Damaged,
+            2 => PanelState::Hanging,
+            3 => PanelState::Removed,
+            other => PanelState::Other(other),
+        }
+    }
+}
+
+impl From<PanelState> for u8 {
+    fn from(state: PanelState) -> u8 {
+        match state {
+            PanelState::Undamaged => 0,
+            PanelState::Damaged => 1,
+            PanelState::Hanging => 2,
+            PanelState::Removed => 3,
+            PanelState::Other(other) => other & 0xF,
+        }
+    }
+}
+
+/// Seven 4-bit panel states packed from the lowest nibble up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PanelDamage {
+    pub front_left: PanelState,
+    pub front_right: PanelState,
+    pub rear_left: PanelState,
+    pub rear_right: PanelState,
+    pub windshield: PanelState,
+    pub front_bumper: PanelState,
+    pub rear_bumper: PanelState,
+    /// The eighth nibble, unused by GTA.
+    pub other: u8,
+}
+
+impl PanelDamage {
+    fn panels(&self) -> [PanelState; 7] {
+        [
+            self.front_left,
+            self.front_right,
+            self.rear_left,
+            self.rear_right,
+            self.windshield,
+            self.front_bumper,
+            self.rear_bumper,
+        ]
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.panels()
+            .iter()
+            .any(|&panel| panel != PanelState::Undamaged)
+    }
+}
+
+impl From<u32> for PanelDamage {
+    fn from(raw: u32) -> PanelDamage {
+        let panel = |index: u32| PanelState::from((raw >> (index * 4)) as u8 & 0xF);
+
+        PanelDamage {
+            front_left: panel(0),
+            front_right: panel(1),
+            rear_left: panel(2),
+            rear_right: panel(3),
+            windshield: panel(4),
+            front_bumper: panel(5),
+            rear_bumper: panel(6),
+            other: (raw >> 28) as u8,
+        }
+    }
+}
+
+impl From<PanelDamage> for u32 {
+    fn from(damage: PanelDamage) -> u32 {
+        damage
+            .panels()
+            .iter()
+            .enumerate()
+            .fold((damage.other as u32 & 0xF) << 28, |raw, (index, &panel)| {
+                raw | (u8::from(panel) as u32) << (index * 4)
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DoorState {
+    pub opened: bool,
+    pub damaged: bool,
+    pub missing: bool,
+    /// Bits 3-7 as they were set, GTA doesn't use them.
+    pub other: u8,
+}
+
+impl From<u8> for DoorState {
+    fn from(state: u8) -> DoorState {
+        DoorState {
+            opened: state & 0b001 != 0,
+            damaged: state & 0b010 != 0,
+            missing: state & 0b100 != 0,
+            other: state & !0b111,
+        }
+    }
+}
+
+impl From<DoorState> for u8 {
+    fn from(state: DoorState) -> u8 {
+        (state.opened as u8)
+            | (state.damaged as u8) << 1
+            | (state.missing as u8) << 2
+            | state.other & !0b111
+    }
+}
+
+/// Four door states, one byte each: hood, trunk, driver, passenger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DoorDamage {
+    pub hood: DoorState,
+    pub trunk: DoorState,
+    pub driver: DoorState,
+    pub passenger: DoorState,
+}
+
+impl DoorDamage {
+    pub fn is_damaged(&self) -> bool {
+        [self.hood, self.trunk, self.driver, self.passenger]
+            .iter()
+            .any(|door| door.damaged || door.missing)
+    }
+}
+
+impl From<u32> for DoorDamage {
+    fn from(raw: u32) -> DoorDamage {
+        let door = |index: u32| DoorState::from((raw >> (index * 8)) as u8);
+
+        DoorDamage {
+            hood: door(0),
+            trunk: door(1),
+            driver: door(2),
+            passenger: door(3),
+        }
+    }
+}
+
+impl From<DoorDamage> for u32 {
+    fn from(damage: DoorDamage) -> u32 {
+        u8::from(damage.hood) as u32
+            | (u8::from(damage.trunk) as u32) << 8
+            | (u8::from(damage.driver) as u32) << 16
+            | (u8::from(damage.passenger) as u32) << 24
+    }
+}
+
+/// Broken lights, every second bit starting from the front left one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LightDamage {
+    pub front_left: bool,
+    pub front_right: bool,
+    pub rear_right: bool,
+    pub rear_left: bool,
+    /// The odd bits as they were set, GTA doesn't use them.
+    pub other: u8,
+}
+
+impl LightDamage {
+    pub fn is_damaged(&self) -> bool {
+        self.front_left || self.front_right || self.rear_right || self.rear_left
+    }
+}
+
+impl From<u8> for LightDamage {
+    fn from(raw: u8) -> LightDamage {
+        LightDamage {
+            front_left: raw & 0b0000_0001 != 0,
+            front_right: raw & 0b0000_0100 != 0,
+            rear_right: raw & 0b0001_0000 != 0,
+            rear_left: raw & 0b0100_0000 != 0,
+            other: raw & 0b1010_1010,
+        }
+    }
+}
+
+impl From<LightDamage> for u8 {
+    fn from(damage: LightDamage) -> u8 {
+        (damage.front_left as u8)
+            | (damage.front_right as u8) << 2
+            | (damage.rear_right as u8) << 4
+            | (damage.rear_left as u8) << 6
+            | damage.other & 0b1010_1010
+    }
+}
+
+/// Popped tires. Bikes only use the two lowest bits: rear (`rear_right`) and front (`front_right`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TireDamage {
+    pub rear_right: bool,
+    pub front_right: bool,
+    pub rear_left: bool,
+    pub front_left: bool,
+    /// The high nibble as it was set, GTA doesn't use it.
+    pub other: u8,
+}
+
+impl TireDamage {
+    pub fn is_damaged(&self) -> bool {
+        self.rear_right || self.front_right || self.rear_left || self.front_left
+    }
+
+    pub fn bike_front(&self) -> bool {
+        self.front_right
+    }
+
+    pub fn bike_rear(&self) -> bool {
+        self.rear_right
+    }
+}
+
+impl From<u8> for TireDamage {
+    fn from(raw: u8) -> TireDamage {
+        TireDamage {
+            rear_right: raw & 0b0001 != 0,
+            front_right: raw & 0b0010 != 0,
+            rear_left: raw & 0b0100 != 0,
+            front_left: raw & 0b1000 != 0,
+            other: raw & 0xF0,
+        }
+    }
+}
+
+impl From<TireDamage> for u8 {
+    fn from(damage: TireDamage) -> u8 {
+        (damage.rear_right as u8)
+            | (damage.front_right as u8) << 1
+            | (damage.rear_left as u8) << 2
+            | (damage.front_left as u8) << 3
+            | damage.other & 0xF0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VehicleDamage {
+    pub panels: PanelDamage,
+    pub doors: DoorDamage,
+    pub lights: LightDamage,
+    /// `None` where samp doesn't keep the tire status, like vehicles waiting to stream in.
+    pub tires: Option<TireDamage>,
+}
+
+impl VehicleDamage {
+    pub fn new(panels: u32, doors: u32, lights: u8, tires: Option<u8>) -> VehicleDamage {
+        VehicleDamage {
+            panels: PanelDamage::from(panels),
+            doors: DoorDamage::from(doors),
+            lights: LightDamage::from(lights),
+            tires: tires.map(TireDamage::from),
+        }
+    }
+
+    /// Panels, doors, lights and tires as samp stores them.
+    pub fn raw(&self) -> (u32, u32, u8, Option<u8>) {
+        (
+            u32::from(self.panels),
+            u32::from(self.doors),
+            u8::from(self.lights),
+            self.tires.map(u8::from),
+        )
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.panels.is_damaged()
+            || self.doors.is_damaged()
+            || self.lights.is_damaged()
+            || self.tires.map_or(false, |tires| tires.is_damaged())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(damage: VehicleDamage) {
+        let (panels, doors, lights, tires) = damage.raw();
+        assert_eq!(VehicleDamage::new(panels, doors, lights, tires), damage);
+    }
+
+    #[test]
+    fn panels_round_trip() {
+        for nibble in 0..16u8 {
+            let state = PanelState::from(nibble);
+            assert_eq!(u8::from(state), nibble);
+
+            for index in 0..8 {
+                let raw = (nibble as u32) << (index * 4);
+                let damage = PanelDamage::from(raw);
+
+                assert_eq!(u32::from(damage), raw);
+                round_trip(VehicleDamage {
+                    panels: damage,
+                    ..VehicleDamage::default()
+                });
+            }
+        }
+
+        assert_eq!(PanelState::from(3), PanelState::Removed);
+        assert_eq!(PanelState::from(5), PanelState::Other(5));
+        assert!(!PanelDamage::from(0xF000_0000).is_damaged());
+
+        for raw in [0x0FED_CBA9, 0xFFFF_FFFF, 0x8000_0001] {
+            assert_eq!(u32::from(PanelDamage::from(raw)), raw);
+        }
+    }
+
+    #[test]
+    fn doors_round_trip() {
+        for state in 0..=255u8 {
+            let door = DoorState::from(state);
+            assert_eq!(u8::from(door), state);
+
+            for index in 0..4 {
+                let raw = (state as u32) << (index * 8);
+                let damage = DoorDamage::from(raw);
+
+                assert_eq!(u32::from(damage), raw);
+                round_trip(VehicleDamage {
+                    doors: damage,
+                    ..VehicleDamage::default()
+                });
+            }
+        }
+
+        let door = DoorState::from(0b1000_0010);
+        assert!(door.damaged && !door.opened && !door.missing);
+        assert_eq!(door.other, 0b1000_0000);
+    }
+
+    #[test]
+    fn lights_round_trip() {
+        for raw in 0..=255u8 {
+            let damage = LightDamage::from(raw);
+
+            assert_eq!(u8::from(damage), raw);
+            assert_eq!(damage.is_damaged(), raw & 0b0101_0101 != 0);
+            round_trip(VehicleDamage {
+                lights: damage,
+                ..VehicleDamage::default()
+            });
+        }
+    }
+
+    #[test]
+    fn tires_round_trip() {
+        for raw in 0..=255u8 {
+            let damage = TireDamage::from(raw);
+
+            assert_eq!(u8::from(damage), raw);
+            assert_eq!(damage.is_damaged(), raw & 0xF != 0);
+            round_trip(VehicleDamage {
+                tires: Some(damage),
+                ..VehicleDamage::default()
+            });
+        }
+
+        assert!(TireDamage::from(0b01).bike_rear());
+        assert!(TireDamage::from(0b10).bike_front());
+    }
+
+    #[test]
+    fn vehicle_round_trip() {
+        let raw = (0x0312_0F21, 0x0407_0201, 0b0100_0101, Some(0b1010));
+        let damage = VehicleDamage::new(raw.0, raw.1, raw.2, raw.3);
+
+        assert_eq!(damage.raw(), raw);
+        assert!(damage.is_damaged());
+        assert!(!VehicleDamage::default().is_damaged());
+
+        let unknown_tires = VehicleDamage::new(0, 0, 0, None);
+
+        assert_eq!(unknown_tires.tires, None);
+        assert_eq!(unknown_tires.raw(), (0, 0, 0, None));
+        assert!(!unknown_tires.is_damaged());
+    }
+}
@@ -1,6 +1,179 @@
 use retour::GenericDetour;
 
+use super::encoding;
 use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::color::Rgba;
+use crate::gta::weapons::{
+    weapon_info, Weapon, REASON_COLLISION, REASON_CONNECT, REASON_DISCONNECT, REASON_DROWNED,
+    REASON_EXPLOSION, REASON_HELICOPTER_BLADES, REASON_VEHICLE,
+};
+
+pub const MAX_ENTRIES: usize = 5;
+
+/// What the icon of a kill list entry shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillReason {
+    Weapon(u8),
+    Vehicle,
+    HelicopterBlades,
+    Explosion,
+    Drowned,
+    Collision,
+    Connected,
+    Disconnected,
+    Suicide,
+    Other(u8),
+}
+
+impl From<u8> for KillReason {
+    fn from(reason: u8) -> KillReason {
+        match reason {
+            0..=46 => KillReason::Weapon(reason),
+            REASON_VEHICLE => KillReason::Vehicle,
+            REASON_HELICOPTER_BLADES => KillReason::HelicopterBlades,
+            REASON_EXPLOSION => KillReason::Explosion,
+            REASON_DROWNED => KillReason::Drowned,
+            REASON_COLLISION => KillReason::Collision,
+            REASON_CONNECT => KillReason::Connected,
+            REASON_DISCONNECT => KillReason::Disconnected,
+            255 => KillReason::Suicide,
+            _ => KillReason::Other(reason),
+        }
+    }
+}
+
+/// A kill list entry, `SendDeathMessage` on the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KillEvent {
+    /// `None` when nobody killed the victim.
+    pub killer: Option<String>,
+    pub killer_color: Rgba,
+    pub victim: String,
+    pub victim_color: Rgba,
+    /// Raw reason byte, see `reason()`.
+    pub weapon: u8,
+}
+
+impl KillEvent {
+    pub(super) fn new(
+        killer: String,
+        killer_color: u32,
+        victim: String,
+        victim_color: u32,
+        weapon: u8,
+    ) -> KillEvent {
+        KillEvent {
+            killer: if killer.is_empty() { None } else { Some(killer) },
+            killer_color: Rgba::from_argb(killer_color),
+            victim,
+            victim_color: Rgba::from_argb(victim_color),
+            weapon,
+        }
+    }
+
+    pub fn reason(&self) -> KillReason {
+        KillReason::from(self.weapon)
+    }
+
+    pub fn weapon_info(&self) -> Option<&'static Weapon> {
+        match self.reason() {
+            KillReason::Weapon(weapon) => weapon_info(weapon as i32),
+            _ => None,
+        }
+    }
+}
+
+type AddEntryFn = extern "thiscall" fn(
+    *mut (),
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    std::os::raw::c_ulong,
+    std::os::raw::c_ulong,
+    std::os::raw::c_char,
+);
+
+struct AddEntryHook {
+    hook: GenericDetour<AddEntryFn>,
+    callback: Box<dyn FnMut(&KillEvent)>,
+}
+
+static mut ADD_ENTRY_HOOK: Option<AddEntryHook> = None;
+
+extern "thiscall" fn add_entry(
+    this: *mut (),
+    killer: *const std::os::raw::c_char,
+    victim: *const std::os::raw::c_char,
+    killer_color: std::os::raw::c_ulong,
+    victim_color: std::os::raw::c_ulong,
+    weapon: std::os::raw::c_char,
+) {
+    unsafe {
+        if let Some(hook) = ADD_ENTRY_HOOK.as_mut() {
+            hook.hook.call(this, killer, victim, killer_color, victim_color, weapon);
+
+            let event = KillEvent::new(
+                encoding::decode_c_str(killer),
+                killer_color as u32,
+                encoding::decode_c_str(victim),
+                victim_color as u32,
+                weapon as u8,
+            );
+
+            (hook.callback)(&event);
+        }
+    }
+}
+
+/// Entries of the kill list, the oldest first.
+pub fn entries() -> Vec<KillEvent> {
+    let entries = match version() {
+        Version::V037 => r1::deathwindow()
+            .map(|window| window.m_entry.iter().filter_map(|entry| entry.event()).collect()),
+        Version::V037R3 => r3::deathwindow()
+            .map(|window| window.m_entry.iter().filter_map(|entry| entry.event()).collect()),
+        Version::V03DLR1 => dl::deathwindow()
+            .map(|window| window.m_entry.iter().filter_map(|entry| entry.event()).collect()),
+        _ => None,
+    };
+
+    entries.unwrap_or_else(Vec::new)
+}
+
+pub fn is_enabled() -> bool {
+    match version() {
+        Version::V037 => r1::deathwindow().map(|window| window.m_bEnabled != 0),
+        Version::V037R3 => r3::deathwindow().map(|window| window.m_bEnabled != 0),
+        Version::V03DLR1 => dl::deathwindow().map(|window| window.m_bEnabled != 0),
+        _ => None,
+    }
+    .unwrap_or(false)
+}
+
+/// Called for every new kill list entry, including connect/disconnect ones.
+pub fn on_kill<F: FnMut(&KillEvent) + 'static>(callback: F) {
+    let address = match version() {
+        Version::V037 => r1::CDEATHWINDOW_ADDENTRY,
+        Version::V037R3 => r3::CDEATHWINDOW_ADDENTRY,
+        Version::V03DLR1 => dl::CDEATHWINDOW_ADDENTRY,
+        _ => return,
+    };
+
+    unsafe {
+        let ptr = super::handle().add(address);
+        let func: AddEntryFn = std::mem::transmute(ptr);
+
+        if let Ok(hook) = GenericDetour::new(func, add_entry) {
+            let _ = hook.enable();
+
+            ADD_ENTRY_HOOK = Some(AddEntryHook {
+                hook,
+                callback: Box::new(callback),
+            });
+        }
+    }
+}
 
 struct DeathWindowDrawHook {
     hook: GenericDetour<extern "thiscall" fn(*mut ())>,
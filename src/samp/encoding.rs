@@ -0,0 +1,133 @@
+// samp passes text around in the system ANSI codepage, not UTF-8.
+// Only the two codepages seen in practice are supported: Western (1252) and Cyrillic (1251).
+
+use std::os::raw::c_char;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codepage {
+    Windows1251,
+    Windows1252,
+}
+
+static mut CODEPAGE: Codepage = Codepage::Windows1252;
+
+const WINDOWS_1251: [char; 64] = [
+    'Ђ', 'Ѓ', '‚', 'ѓ', '„', '…', '†', '‡', '€', '‰', 'Љ', '‹', 'Њ', 'Ќ', 'Ћ', 'Џ',
+    'ђ', '‘', '’', '“', '”', '•', '–', '—', '\u{98}', '™', 'љ', '›', 'њ', 'ќ', 'ћ', 'џ',
+    '\u{A0}', 'Ў', 'ў', 'Ј', '¤', 'Ґ', '¦', '§', 'Ё', '©', 'Є', '«', '¬', '\u{AD}', '®', 'Ї',
+    '°', '±', 'І', 'і', 'ґ', 'µ', '¶', '·', 'ё', '№', 'є', '»', 'ј', 'Ѕ', 'ѕ', 'ї',
+];
+
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Sets the codepage used for every string read from or written into samp.
+pub fn set_codepage(codepage: Codepage) {
+    unsafe {
+        CODEPAGE = codepage;
+    }
+}
+
+pub fn codepage() -> Codepage {
+    unsafe { CODEPAGE }
+}
+
+fn decode_byte(codepage: Codepage, byte: u8) -> char {
+    match (codepage, byte) {
+        (_, 0x00..=0x7F) => byte as char,
+        (Codepage::Windows1251, 0x80..=0xBF) => WINDOWS_1251[byte as usize - 0x80],
+        (Codepage::Windows1251, _) => char::from_u32(0x0410 + (byte as u32 - 0xC0)).unwrap(),
+        (Codepage::Windows1252, 0x80..=0x9F) => WINDOWS_1252[byte as usize - 0x80],
+        (Codepage::Windows1252, _) => byte as char,
+    }
+}
+
+fn encode_char(codepage: Codepage, ch: char) -> Option<u8> {
+    if (ch as u32) < 0x80 {
+        return Some(ch as u8);
+    }
+
+    let position = match codepage {
+        Codepage::Windows1251 => {
+            if ('\u{0410}'..='\u{044F}').contains(&ch) {
+                return Some((ch as u32 - 0x0410 + 0xC0) as u8);
+            }
+
+            WINDOWS_1251.iter().position(|&other| other == ch)
+        }
+
+        Codepage::Windows1252 => {
+            if ('\u{A0}'..='\u{FF}').contains(&ch) {
+                return Some(ch as u8);
+            }
+
+            WINDOWS_1252.iter().position(|&other| other == ch)
+        }
+    };
+
+    position.map(|position| position as u8 + 0x80)
+}
+
+pub fn decode(bytes: &[u8]) -> String {
+    let codepage = codepage();
+    bytes.iter().map(|&byte| decode_byte(codepage, byte)).collect()
+}
+
+/// Characters missing in the codepage are replaced with `?`, as `WideCharToMultiByte` does.
+pub fn encode(text: &str) -> Vec<u8> {
+    let codepage = codepage();
+
+    text.chars()
+        .map(|ch| encode_char(codepage, ch).unwrap_or(b'?'))
+        .collect()
+}
+
+/// Encoded text with a trailing NUL, ready to be passed to samp as `const char*`.
+pub fn encode_c_string(text: &str) -> Vec<u8> {
+    let mut bytes: Vec<u8> = encode(text).into_iter().filter(|&byte| byte != 0).collect();
+    bytes.push(0);
+    bytes
+}
+
+/// Bytes of a fixed-size `char` buffer up to the first NUL.
+pub fn c_buffer_bytes(buffer: &[c_char]) -> &[u8] {
+    let len = buffer.iter().position(|&ch| ch == 0).unwrap_or(buffer.len());
+    unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const u8, len) }
+}
+
+pub fn decode_c_buffer(buffer: &[c_char]) -> String {
+    decode(c_buffer_bytes(buffer))
+}
+
+/// Decodes a NUL-terminated string, a null pointer gives an empty string.
+pub fn decode_c_str(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    let bytes = unsafe { std::ffi::CStr::from_ptr(ptr).to_bytes() };
+    decode(bytes)
+}
+
+/// Writes encoded text into a fixed-size `char` buffer, truncating it to leave room for NUL.
+pub fn write_c_buffer(buffer: &mut [c_char], text: &str) -> usize {
+    if buffer.is_empty() {
+        return 0;
+    }
+
+    let bytes = encode(text);
+    let len = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(bytes.len())
+        .min(buffer.len() - 1);
+
+    for (dst, &src) in buffer.iter_mut().zip(&bytes[..len]) {
+        *dst = src as c_char;
+    }
+
+    buffer[len] = 0;
+    len
+}
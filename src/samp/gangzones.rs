@@ -0,0 +1,76 @@
+use super::players;
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::color::Rgba;
+use crate::gta::matrix::CVector;
+
+pub const MAX_GANG_ZONES: usize = 1024;
+
+/// Copy of a gang zone, the rectangle is in world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GangZone {
+    pub id: u16,
+    pub min: (f32, f32),
+    pub max: (f32, f32),
+    pub color: Rgba,
+    pub flashing: bool,
+    /// Color the zone alternates with while flashing, equals `color` otherwise.
+    pub flash_color: Rgba,
+}
+
+impl GangZone {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.min.0 && x <= self.max.0 && y >= self.min.1 && y <= self.max.1
+    }
+
+    /// Gang zones have no height, so only x and y of `point` matter.
+    pub fn contains_point(&self, point: &CVector) -> bool {
+        self.contains(point.x, point.y)
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        ((self.min.0 + self.max.0) / 2.0, (self.min.1 + self.max.1) / 2.0)
+    }
+}
+
+/// Every gang zone shown to the player.
+pub fn gang_zones() -> Vec<GangZone> {
+    let zones = match version() {
+        Version::V037 => r1::gang_zones()
+            .map(|iter| iter.map(|(id, zone)| zone.info(id as u16)).collect()),
+        Version::V037R3 => r3::gang_zones()
+            .map(|iter| iter.map(|(id, zone)| zone.info(id as u16)).collect()),
+        Version::V03DLR1 => dl::gang_zones()
+            .map(|iter| iter.map(|(id, zone)| zone.info(id as u16)).collect()),
+        _ => None,
+    };
+
+    zones.unwrap_or_else(Vec::new)
+}
+
+pub fn gang_zone(zone_id: u16) -> Option<GangZone> {
+    let id = zone_id as i32;
+
+    match version() {
+        Version::V037 => r1::find_gang_zone(id).map(|zone| zone.info(zone_id)),
+        Version::V037R3 => r3::find_gang_zone(id).map(|zone| zone.info(zone_id)),
+        Version::V03DLR1 => dl::find_gang_zone(id).map(|zone| zone.info(zone_id)),
+        _ => None,
+    }
+}
+
+/// Zones covering `point`. They can overlap, samp draws the ones with higher ids on top.
+pub fn zones_at(point: &CVector) -> Vec<GangZone> {
+    gang_zones()
+        .into_iter()
+        .filter(|zone| zone.contains_point(point))
+        .collect()
+}
+
+/// Zones the local player stands in, the topmost last.
+pub fn local_player_zones() -> Vec<GangZone> {
+    players::local_player()
+        .map(|player| zones_at(&player.position()))
+        .unwrap_or_else(Vec::new)
+}
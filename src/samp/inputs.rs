@@ -1,12 +1,41 @@
 use super::{handle, BOOL};
+use super::encoding;
 use super::version::{Version, version};
 
+use retour::GenericDetour;
 use std::ffi::c_void;
 
 pub type CMDPROC = Option<unsafe extern "C" fn(arg1: *const std::os::raw::c_char)>;
 
-const MAX_CLIENT_CMDS: usize = 144;
-const MAX_CMD_LENGTH: usize = 32;
+pub const MAX_CLIENT_CMDS: usize = 144;
+pub const MAX_CMD_LENGTH: usize = 32;
+pub const MAX_RECALLS: usize = 10;
+/// Length limit of the chat input box, longer text is cut by the server anyway.
+pub const MAX_INPUT_LENGTH: usize = 128;
+/// Length limit samp puts on the dialog input box, the server gets at most that much `inputtext`.
+pub const MAX_DIALOG_INPUT_LENGTH: usize = 128;
+
+struct ProcessInputHook {
+    hook: GenericDetour<extern "thiscall" fn(*mut Input)>,
+    callback: Box<dyn FnMut(&str) -> bool>,
+}
+
+static mut PROCESS_INPUT_HOOK: Option<ProcessInputHook> = None;
+
+extern "thiscall" fn process_input(this: *mut Input) {
+    unsafe {
+        if let Some(hook) = PROCESS_INPUT_HOOK.as_mut() {
+            let text = Input::text().unwrap_or_default();
+
+            if (hook.callback)(&text) {
+                Input::set_text("");
+                Input::close();
+            } else {
+                hook.hook.call(this);
+            }
+        }
+    }
+}
 
 #[repr(C, packed)]
 pub struct Input {
@@ -18,7 +47,7 @@ pub struct Input {
     pub m_nCommandCount: std::os::raw::c_int,
     pub m_bEnabled: BOOL,
     pub m_szInput: [std::os::raw::c_char; 129],
-    pub m_szRecallBufffer: [[std::os::raw::c_char; 129]; 10],
+    pub m_szRecallBufffer: [[std::os::raw::c_char; 129]; MAX_RECALLS],
     pub m_szCurrentBuffer: [std::os::raw::c_char; 129],
     pub m_nCurrentRecall: std::os::raw::c_int,
     pub m_nTotalRecall: std::os::raw::c_int,
@@ -48,6 +77,126 @@ impl Input {
             .map(|input| input.m_bEnabled == 1)
             .unwrap_or(false)
     }
+
+    fn call(address: (usize, usize, usize)) {
+        let address = match version() {
+            Version::V037 => address.0,
+            Version::V037R3 => address.1,
+            Version::V03DLR1 => address.2,
+            _ => return,
+        };
+
+        if let Some(input) = Self::get() {
+            unsafe {
+                let func: extern "thiscall" fn(*mut Input) =
+                    std::mem::transmute(handle().add(address));
+
+                func(input);
+            }
+        }
+    }
+
+    /// Opens the chat input like pressing T/F6 does.
+    pub fn open() {
+        Self::call((
+            super::v037::CINPUT_OPEN,
+            super::v037r3::CINPUT_OPEN,
+            super::v03dlr1::CINPUT_OPEN,
+        ));
+    }
+
+    pub fn close() {
+        Self::call((
+            super::v037::CINPUT_CLOSE,
+            super::v037r3::CINPUT_CLOSE,
+            super::v03dlr1::CINPUT_CLOSE,
+        ));
+    }
+
+    fn editbox<'a>() -> Option<&'a mut DXUTEditBox> {
+        Self::get()
+            .filter(|input| !input.m_pEditbox.is_null())
+            .map(|input| unsafe { &mut *(input.m_pEditbox as *mut DXUTEditBox) })
+    }
+
+    /// Text typed in the input box right now.
+    pub fn text() -> Option<String> {
+        Self::editbox().map(|editbox| editbox.text())
+    }
+
+    /// Replaces the input text and moves the cursor to its end.
+    pub fn set_text(text: &str) {
+        if let Some(editbox) = Self::editbox() {
+            editbox.set_text(text, MAX_INPUT_LENGTH);
+        }
+    }
+
+    /// Cursor position in UTF-16 units.
+    pub fn cursor() -> Option<usize> {
+        Self::editbox().map(|editbox| editbox.caret.max(0) as usize)
+    }
+
+    pub fn set_cursor(position: usize) {
+        if let Some(editbox) = Self::editbox() {
+            editbox.set_cursor(position);
+        }
+    }
+
+    /// Previously entered lines, the newest first.
+    pub fn recall_history() -> Vec<String> {
+        Self::get()
+            .map(|input| {
+                let total = (input.m_nTotalRecall.max(0) as usize).min(MAX_RECALLS);
+
+                input.m_szRecallBufffer[..total]
+                    .iter()
+                    .map(|line| encoding::decode_c_buffer(line))
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// Adds a line to the history the same way samp does after Enter.
+    pub fn add_to_recall(text: &str) {
+        if let Some(input) = Self::get() {
+            for index in (1..MAX_RECALLS).rev() {
+                input.m_szRecallBufffer[index] = input.m_szRecallBufffer[index - 1];
+            }
+
+            encoding::write_c_buffer(&mut input.m_szRecallBufffer[0], text);
+
+            if (input.m_nTotalRecall as usize) < MAX_RECALLS - 1 {
+                input.m_nTotalRecall += 1;
+            }
+
+            input.m_nCurrentRecall = -1;
+        }
+    }
+
+    /// Called with the input text when Enter is pressed. Returning `true` swallows the line:
+    /// the input is cleared and closed without sending anything.
+    pub fn on_enter<F: FnMut(&str) -> bool + 'static>(callback: F) {
+        let address = match version() {
+            Version::V037 => super::v037::CINPUT_PROCESSINPUT,
+            Version::V037R3 => super::v037r3::CINPUT_PROCESSINPUT,
+            Version::V03DLR1 => super::v03dlr1::CINPUT_PROCESSINPUT,
+            _ => return,
+        };
+
+        unsafe {
+            let ptr = handle().add(address);
+            let func: extern "thiscall" fn(*mut Input) = std::mem::transmute(ptr);
+
+            if let Ok(hook) = GenericDetour::new(func, process_input) {
+                let _ = hook.enable();
+
+                PROCESS_INPUT_HOOK = Some(ProcessInputHook {
+                    hook,
+                    callback: Box::new(callback),
+                });
+            }
+        }
+    }
 }
 
 #[repr(C)]
@@ -59,6 +208,290 @@ pub struct DXUTControl {
     is_default: bool, // Is the default control
 }
 
+/// Byte offset of a field of a packed layout, `offset_of!` isn't stable on our toolchain.
+#[cfg(target_pointer_width = "32")]
+macro_rules! field_offset {
+    ($type:ty, $field:ident) => {{
+        let object = std::mem::MaybeUninit::<$type>::uninit();
+        let base = object.as_ptr();
+
+        unsafe { (std::ptr::addr_of!((*base).$field) as *const u8).offset_from(base as *const u8) }
+    }};
+}
+
+// The DXUT layouts below follow DXUTgui.h of the DirectX SDK, which samp.dll links statically,
+// as laid out by 32-bit MSVC: CDXUTControl is 80 bytes (vftable, 4 flags, x/y/width/height,
+// m_pDialog, m_Index, m_ID, m_Type, m_nHotkey, m_pUserData, m_bEnabled, m_rcBoundingBox and
+// the m_Elements array), CUniBuffer is 20 and CDXUTScrollBar is 184. The asserts pin the
+// offsets of every field read or written here.
+
+// CDXUTEditBox, only the fields needed for text and caret access.
+#[repr(C, packed)]
+pub struct DXUTEditBox {
+    pub control: DXUTControl,
+    pub pad_0: [u8; 72], // rest of CDXUTControl
+    pub buffer: *mut u16, // CUniBuffer::m_pwszBuffer
+    pub buffer_size: std::os::raw::c_int,
+    pub pad_1: [u8; 12], // m_pFontNode, m_bAnalyseRequired, m_Analysis
+    pub pad_2: [u8; 168], // m_nBorder, m_nSpacing, m_rcText, m_rcRender[9]
+    pub pad_3: [u8; 4], // alignment of m_dfBlink
+    pub pad_4: [u8; 16], // m_dfBlink, m_dfLastBlink
+    pub caret_on: bool,
+    pub pad_5: [u8; 3],
+    pub caret: std::os::raw::c_int,
+    pub insert_mode: bool,
+    pub pad_6: [u8; 3],
+    pub selection_start: std::os::raw::c_int,
+    pub first_visible: std::os::raw::c_int,
+}
+
+#[cfg(target_pointer_width = "32")]
+const _: () = {
+    assert!(std::mem::size_of::<DXUTControl>() == 8);
+    assert!(field_offset!(DXUTEditBox, buffer) == 80);
+    assert!(field_offset!(DXUTEditBox, buffer_size) == 84);
+    assert!(field_offset!(DXUTEditBox, caret_on) == 288);
+    assert!(field_offset!(DXUTEditBox, caret) == 292);
+    assert!(field_offset!(DXUTEditBox, selection_start) == 300);
+    assert!(field_offset!(DXUTEditBox, first_visible) == 304);
+};
+
+impl DXUTEditBox {
+    fn text_len(&self) -> usize {
+        if self.buffer.is_null() {
+            return 0;
+        }
+
+        let size = self.buffer_size.max(0) as usize;
+        unsafe { (0..size).take_while(|&index| *self.buffer.add(index) != 0).count() }
+    }
+
+    pub fn text(&self) -> String {
+        if self.buffer.is_null() {
+            return String::new();
+        }
+
+        let text = unsafe { std::slice::from_raw_parts(self.buffer, self.text_len()) };
+        String::from_utf16_lossy(text)
+    }
+
+    /// Replaces the text (cut to `max_length` UTF-16 units) and moves the cursor to its end.
+    pub fn set_text(&mut self, text: &str, max_length: usize) {
+        let address = match version() {
+            Version::V037 => super::v037::CDXUTEDITBOX_SETTEXT,
+            Version::V037R3 => super::v037r3::CDXUTEDITBOX_SETTEXT,
+            Version::V03DLR1 => super::v03dlr1::CDXUTEDITBOX_SETTEXT,
+            _ => return,
+        };
+
+        let mut wide: Vec<u16> = text.encode_utf16().take(max_length).collect();
+        wide.push(0);
+
+        unsafe {
+            let set_text: extern "thiscall" fn(*mut DXUTEditBox, *const u16, bool) =
+                std::mem::transmute(handle().add(address));
+
+            set_text(self, wide.as_ptr(), false);
+        }
+
+        self.set_cursor(wide.len() - 1);
+    }
+
+    pub fn set_cursor(&mut self, position: usize) {
+        let position = position.min(self.text_len()) as std::os::raw::c_int;
+
+        self.caret = position;
+        self.selection_start = position;
+        self.caret_on = true;
+
+        if self.first_visible > position {
+            self.first_visible = position;
+        }
+    }
+}
+
+// CDXUTListBox, only the selection and scroll state.
+#[repr(C, packed)]
+pub struct DXUTListBox {
+    pub control: DXUTControl,
+    pub pad_0: [u8; 72],  // rest of CDXUTControl
+    pub pad_1: [u8; 32],  // m_rcText, m_rcSelection
+    pub pad_2: [u8; 148], // CDXUTScrollBar up to m_nPosition
+    pub scroll_position: std::os::raw::c_int,
+    pub scroll_page_size: std::os::raw::c_int,
+    pub scroll_start: std::os::raw::c_int,
+    pub scroll_end: std::os::raw::c_int,
+    pub pad_3: [u8; 20], // m_LastMouse, m_Arrow, m_dArrowTS
+    pub scroll_bar_width: std::os::raw::c_int,
+    pub border: std::os::raw::c_int,
+    pub margin: std::os::raw::c_int,
+    pub text_height: std::os::raw::c_int,
+    pub style: std::os::raw::c_ulong,
+    pub selected: std::os::raw::c_int,
+    pub selection_start: std::os::raw::c_int,
+    pub drag: bool,
+    pub pad_4: [u8; 3],
+    pub items: *mut *mut (), // CGrowableArray<DXUTListBoxItem*>
+    pub item_count: std::os::raw::c_int,
+    pub item_capacity: std::os::raw::c_int,
+}
+
+#[cfg(target_pointer_width = "32")]
+const _: () = {
+    assert!(field_offset!(DXUTListBox, scroll_position) == 112 + 148);
+    assert!(field_offset!(DXUTListBox, scroll_page_size) == 264);
+    assert!(field_offset!(DXUTListBox, scroll_bar_width) == 112 + 184);
+    assert!(field_offset!(DXUTListBox, selected) == 316);
+    assert!(field_offset!(DXUTListBox, selection_start) == 320);
+    assert!(field_offset!(DXUTListBox, items) == 328);
+    assert!(field_offset!(DXUTListBox, item_count) == 332);
+};
+
+impl DXUTListBox {
+    pub fn selected(&self) -> Option<usize> {
+        if self.selected >= 0 && self.selected < self.item_count {
+            Some(self.selected as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Selects a row and scrolls the list so it's visible.
+    pub fn select(&mut self, row: usize) -> bool {
+        if row >= self.item_count.max(0) as usize {
+            return false;
+        }
+
+        let row = row as std::os::raw::c_int;
+
+        self.selected = row;
+        self.selection_start = row;
+
+        if row < self.scroll_position {
+            self.scroll_position = row;
+        } else if row >= self.scroll_position + self.scroll_page_size {
+            self.scroll_position = row - self.scroll_page_size + 1;
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogStyle {
+    MessageBox,
+    Input,
+    List,
+    Password,
+    Tablist,
+    TablistHeaders,
+}
+
+impl DialogStyle {
+    pub fn from_raw(style: i32) -> Option<DialogStyle> {
+        match style {
+            0 => Some(DialogStyle::MessageBox),
+            1 => Some(DialogStyle::Input),
+            2 => Some(DialogStyle::List),
+            3 => Some(DialogStyle::Password),
+            4 => Some(DialogStyle::Tablist),
+            5 => Some(DialogStyle::TablistHeaders),
+            _ => None,
+        }
+    }
+
+    pub fn has_input(&self) -> bool {
+        matches!(self, DialogStyle::Input | DialogStyle::Password)
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(
+            self,
+            DialogStyle::List | DialogStyle::Tablist | DialogStyle::TablistHeaders
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogButton {
+    /// The first button, `response = 1` on the server.
+    Left,
+    /// The second button or Esc, `response = 0` on the server.
+    Right,
+}
+
+/// Everything samp got in `ShowPlayerDialog`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShownDialog {
+    pub id: i32,
+    pub style: Option<DialogStyle>,
+    pub caption: String,
+    pub body: String,
+    pub left_button: String,
+    pub right_button: String,
+    pub serverside: bool,
+}
+
+type ShowFn = extern "thiscall" fn(
+    *mut Dialog,
+    std::os::raw::c_int,
+    std::os::raw::c_int,
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    BOOL,
+);
+
+struct ShowHook {
+    hook: GenericDetour<ShowFn>,
+    callback: Box<dyn FnMut(&ShownDialog)>,
+}
+
+static mut SHOW_HOOK: Option<ShowHook> = None;
+// button labels aren't kept by CDialog, so they are taken from the last Show call
+static mut LAST_SHOWN: Option<ShownDialog> = None;
+
+#[allow(clippy::too_many_arguments)]
+extern "thiscall" fn show(
+    this: *mut Dialog,
+    id: std::os::raw::c_int,
+    style: std::os::raw::c_int,
+    caption: *const std::os::raw::c_char,
+    text: *const std::os::raw::c_char,
+    left_button: *const std::os::raw::c_char,
+    right_button: *const std::os::raw::c_char,
+    serverside: BOOL,
+) {
+    unsafe {
+        if let Some(hook) = SHOW_HOOK.as_mut() {
+            hook.hook.call(
+                this,
+                id,
+                style,
+                caption,
+                text,
+                left_button,
+                right_button,
+                serverside,
+            );
+
+            let shown = ShownDialog {
+                id,
+                style: DialogStyle::from_raw(style),
+                caption: encoding::decode_c_str(caption),
+                body: encoding::decode_c_str(text),
+                left_button: encoding::decode_c_str(left_button),
+                right_button: encoding::decode_c_str(right_button),
+                serverside: serverside != 0,
+            };
+
+            LAST_SHOWN = Some(shown.clone());
+            (hook.callback)(&shown);
+        }
+    }
+}
+
 #[repr(C, packed)]
 pub struct Dialog {
     pub m_pDevice: *mut (), // IDirect3DDevice9
@@ -66,7 +499,7 @@ pub struct Dialog {
     pub m_size: [std::os::raw::c_ulong; 2],
     pub m_buttonOffset: [std::os::raw::c_ulong; 2],
     pub m_pDialog: *mut (),           // CDXUTDialog
-    pub m_pListbox: *mut (),          // CDXUTListBox
+    pub m_pListbox: *mut DXUTListBox, // CDXUTListBox
     pub m_pEditbox: *mut DXUTControl, // CDXUTIMEEditBox
     pub m_bIsActive: BOOL,
     pub m_nType: std::os::raw::c_int,
@@ -79,6 +512,10 @@ pub struct Dialog {
 
 impl Dialog {
     pub fn get<'a>() -> Option<&'a Dialog> {
+        Dialog::get_mut().map(|cdialog| &*cdialog)
+    }
+
+    pub fn get_mut<'a>() -> Option<&'a mut Dialog> {
         let dialog_addr = match version() {
             Version::V037 => super::v037::CDIALOG,
             Version::V037R3 => super::v037r3::CDIALOG,
@@ -93,7 +530,7 @@ impl Dialog {
             let cdialog = ptr.read();
 
             if !cdialog.is_null() {
-                Some(&*cdialog)
+                Some(&mut *cdialog)
             } else {
                 None
             }
@@ -114,6 +551,230 @@ impl Dialog {
             })
             .unwrap_or(false)
     }
+
+    /// The dialog that is on the screen now.
+    pub fn active<'a>() -> Option<&'a Dialog> {
+        Dialog::get().filter(|cdialog| cdialog.is_active())
+    }
+
+    pub fn active_mut<'a>() -> Option<&'a mut Dialog> {
+        Dialog::get_mut().filter(|cdialog| cdialog.is_active())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.m_bIsActive != 0
+    }
+
+    pub fn id(&self) -> i32 {
+        self.m_nId
+    }
+
+    pub fn style(&self) -> Option<DialogStyle> {
+        DialogStyle::from_raw(self.m_nType)
+    }
+
+    pub fn is_serverside(&self) -> bool {
+        self.m_bServerside != 0
+    }
+
+    pub fn caption(&self) -> String {
+        encoding::decode_c_buffer(&{ self.m_szCaption })
+    }
+
+    /// Raw body with color tags, for list styles it's the item lines.
+    pub fn body(&self) -> String {
+        encoding::decode_c_str(self.m_szText)
+    }
+
+    /// Left and right button labels. Only known for dialogs shown after
+    /// `on_dialog_shown` was set, samp doesn't keep them anywhere else.
+    pub fn buttons(&self) -> Option<(String, String)> {
+        unsafe {
+            LAST_SHOWN
+                .as_ref()
+                .filter(|shown| shown.id == self.m_nId && shown.body == self.body())
+                .map(|shown| (shown.left_button.clone(), shown.right_button.clone()))
+        }
+    }
+
+    /// Column headers of a `TablistHeaders` dialog.
+    pub fn headers(&self) -> Option<Vec<String>> {
+        self.style()
+            .and_then(|style| parse_list(style, &self.body()).0)
+    }
+
+    /// Rows of a list dialog, split into columns for tablists.
+    pub fn list_items(&self) -> Vec<Vec<String>> {
+        self.style()
+            .map(|style| parse_list(style, &self.body()).1)
+            .unwrap_or_else(Vec::new)
+    }
+
+    fn listbox_ptr(&self) -> Option<*mut DXUTListBox> {
+        let is_list = self.style().map(|style| style.is_list()).unwrap_or(false);
+
+        if !is_list || self.m_pListbox.is_null() {
+            None
+        } else {
+            Some(self.m_pListbox)
+        }
+    }
+
+    fn editbox_ptr(&self) -> Option<*mut DXUTEditBox> {
+        let has_input = self.style().map(|style| style.has_input()).unwrap_or(false);
+
+        if !has_input || self.m_pEditbox.is_null() {
+            None
+        } else {
+            Some(self.m_pEditbox as *mut DXUTEditBox)
+        }
+    }
+
+    fn listbox(&self) -> Option<&DXUTListBox> {
+        self.listbox_ptr().map(|listbox| unsafe { &*listbox })
+    }
+
+    fn listbox_mut(&mut self) -> Option<&mut DXUTListBox> {
+        self.listbox_ptr().map(|listbox| unsafe { &mut *listbox })
+    }
+
+    fn editbox(&self) -> Option<&DXUTEditBox> {
+        self.editbox_ptr().map(|editbox| unsafe { &*editbox })
+    }
+
+    fn editbox_mut(&mut self) -> Option<&mut DXUTEditBox> {
+        self.editbox_ptr().map(|editbox| unsafe { &mut *editbox })
+    }
+
+    pub fn selected_row(&self) -> Option<usize> {
+        self.listbox().and_then(|listbox| listbox.selected())
+    }
+
+    /// Returns `false` if it isn't a list dialog or there is no such row.
+    pub fn set_selected_row(&mut self, row: usize) -> bool {
+        self.listbox_mut()
+            .map(|listbox| listbox.select(row))
+            .unwrap_or(false)
+    }
+
+    pub fn input_text(&self) -> Option<String> {
+        self.editbox().map(|editbox| editbox.text())
+    }
+
+    /// Returns `false` if the dialog has no input box.
+    pub fn set_input_text(&mut self, text: &str) -> bool {
+        match self.editbox_mut() {
+            Some(editbox) => {
+                editbox.set_text(text, MAX_DIALOG_INPUT_LENGTH);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Closes the dialog as if `button` was clicked. samp sends the response with
+    /// the selected row and the input text, so set them before if needed.
+    pub fn close(&mut self, button: DialogButton) {
+        let address = match version() {
+            Version::V037 => super::v037::CDIALOG_CLOSE,
+            Version::V037R3 => super::v037r3::CDIALOG_CLOSE,
+            Version::V03DLR1 => super::v03dlr1::CDIALOG_CLOSE,
+            _ => return,
+        };
+
+        let button = match button {
+            DialogButton::Left => 1,
+            DialogButton::Right => 0,
+        };
+
+        unsafe {
+            let close: extern "thiscall" fn(*mut Dialog, std::os::raw::c_char) =
+                std::mem::transmute(handle().add(address));
+
+            close(self, button);
+        }
+    }
+
+    /// Answers the active dialog: selects `list_item` and fills `input_text` when given,
+    /// then closes it with `button`. Returns `false` and leaves the dialog open if no dialog
+    /// is shown, the row doesn't exist or the dialog has no input box.
+    pub fn respond(
+        button: DialogButton,
+        list_item: Option<usize>,
+        input_text: Option<&str>,
+    ) -> bool {
+        let cdialog = match Dialog::active_mut() {
+            Some(cdialog) => cdialog,
+            None => return false,
+        };
+
+        if let Some(row) = list_item {
+            if !cdialog.set_selected_row(row) {
+                return false;
+            }
+        }
+
+        if let Some(text) = input_text {
+            if !cdialog.set_input_text(text) {
+                return false;
+            }
+        }
+
+        cdialog.close(button);
+        true
+    }
+
+    /// Called right after samp shows a dialog, whether it came from the server or not.
+    pub fn on_dialog_shown<F: FnMut(&ShownDialog) + 'static>(callback: F) {
+        let address = match version() {
+            Version::V037 => super::v037::CDIALOG_SHOW,
+            Version::V037R3 => super::v037r3::CDIALOG_SHOW,
+            Version::V03DLR1 => super::v03dlr1::CDIALOG_SHOW,
+            _ => return,
+        };
+
+        unsafe {
+            let ptr = handle().add(address);
+            let func: ShowFn = std::mem::transmute(ptr);
+
+            if let Ok(hook) = GenericDetour::new(func, show) {
+                let _ = hook.enable();
+
+                SHOW_HOOK = Some(ShowHook {
+                    hook,
+                    callback: Box::new(callback),
+                });
+            }
+        }
+    }
+}
+
+/// Splits a list dialog body into the header row (for `TablistHeaders`) and item rows.
+/// Plain lists keep tabs inside a single column like samp draws them.
+pub fn parse_list(style: DialogStyle, body: &str) -> (Option<Vec<String>>, Vec<Vec<String>>) {
+    if !style.is_list() {
+        return (None, Vec::new());
+    }
+
+    let body = body.strip_suffix('\n').unwrap_or(body);
+
+    let mut rows = body.split('\n').map(|line| {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        if style == DialogStyle::List {
+            vec![line.to_owned()]
+        } else {
+            line.split('\t').map(|column| column.to_owned()).collect()
+        }
+    });
+
+    let headers = if style == DialogStyle::TablistHeaders {
+        rows.next()
+    } else {
+        None
+    };
+
+    (headers, rows.collect())
 }
 
 pub fn show_cursor(show: bool) {
@@ -1,6 +1,16 @@
 use retour::GenericDetour;
 
 use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::color::{strip_color_tags, Rgba};
+use crate::gta::matrix::CVector;
+
+pub const MAX_LABELS: usize = 2048;
+pub const MAX_PLAYER_LABELS: usize = 1024;
+/// Slots in `CLabelPool`: `MAX_TEXT_LABELS + MAX_PLAYER_TEXT_LABELS + 2` as in sampapi,
+/// the last two are never sent by the server.
+pub const LABEL_POOL_SIZE: usize = MAX_LABELS + MAX_PLAYER_LABELS + 2;
 
 struct LabelPoolDrawHook {
     hook: GenericDetour<extern "thiscall" fn(*mut ())>,
@@ -18,6 +28,52 @@ extern "thiscall" fn labelpool_draw(this: *mut ()) {
     }
 }
 
+/// Copy of a 3D text label.
+#[derive(Debug, Clone)]
+pub struct Label {
+    /// Pool slot, player labels go after the first `MAX_LABELS` global ones.
+    pub id: u16,
+    /// Text with `{RRGGBB}` tags as the server sent it.
+    pub text: String,
+    pub color: Rgba,
+    /// World position, or the offset from the player/vehicle it's attached to.
+    pub position: CVector,
+    pub draw_distance: f32,
+    /// Hidden behind walls when set.
+    pub test_los: bool,
+    pub attached_player: Option<u16>,
+    pub attached_vehicle: Option<u16>,
+}
+
+impl Label {
+    pub fn is_player_label(&self) -> bool {
+        self.id as usize >= MAX_LABELS
+    }
+
+    pub fn plain_text(&self) -> String {
+        strip_color_tags(&self.text)
+    }
+}
+
+/// Every label in the pool.
+pub fn labels() -> Vec<Label> {
+    let labels = match version() {
+        Version::V037 => r1::labels()
+            .map(|iter| iter.map(|(id, label)| label.info(id as u16)).collect()),
+        Version::V037R3 => r3::labels()
+            .map(|iter| iter.map(|(id, label)| label.info(id as u16)).collect()),
+        Version::V03DLR1 => dl::labels()
+            .map(|iter| iter.map(|(id, label)| label.info(id as u16)).collect()),
+        _ => None,
+    };
+
+    labels.unwrap_or_else(Vec::new)
+}
+
+pub fn label(label_id: u16) -> Option<Label> {
+    labels().into_iter().find(|label| label.id == label_id)
+}
+
 #[repr(C)]
 pub struct LabelPool;
 
@@ -0,0 +1,53 @@
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+pub const MAX_MENUS: usize = 128;
+pub const MAX_MENU_ITEMS: usize = 12;
+pub const MAX_COLUMNS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuColumn {
+    pub header: String,
+    pub width: f32,
+    pub rows: Vec<String>,
+}
+
+/// Copy of a server menu (`CreateMenu`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Menu {
+    pub id: u8,
+    pub title: String,
+    pub position: (f32, f32),
+    pub columns: Vec<MenuColumn>,
+    /// `DisableMenu` clears it, rows can't be selected then.
+    pub enabled: bool,
+    /// Per row, `DisableMenuRow` clears it.
+    pub rows_enabled: Vec<bool>,
+}
+
+impl Menu {
+    pub fn row_count(&self) -> usize {
+        self.rows_enabled.len()
+    }
+
+    pub fn is_row_enabled(&self, row: usize) -> bool {
+        self.enabled && self.rows_enabled.get(row).copied().unwrap_or(false)
+    }
+
+    /// Cells of a row, one per column (empty for columns with fewer rows).
+    pub fn row(&self, row: usize) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.rows.get(row).cloned().unwrap_or_default())
+            .collect()
+    }
+}
+
+pub fn active_menu() -> Option<Menu> {
+    match version() {
+        Version::V037 => r1::active_menu().map(|menu| menu.info()),
+        Version::V037R3 => r3::active_menu().map(|menu| menu.info()),
+        Version::V03DLR1 => dl::active_menu().map(|menu| menu.info()),
+        _ => None,
+    }
+}
@@ -2,10 +2,221 @@
 use std::net::SocketAddr;
 
 use super::{v037, v037r3, v03dlr1};
+use super::rpc::BitReader;
 use super::version::{Version, version};
 use retour::GenericDetour;
+use std::sync::Mutex;
 // use crate::samp::Gamestate;
 
+// RakClientInterface vtable, the same in every version.
+const SET_PASSWORD_VTABLE_INDEX: usize = 4;
+const DISCONNECT_VTABLE_INDEX: usize = 2;
+const DISCONNECT_BLOCK_DURATION: std::os::raw::c_uint = 500;
+
+const MAX_HOST_LENGTH: usize = 257;
+const MIN_NICKNAME_LENGTH: usize = 3;
+const MAX_NICKNAME_LENGTH: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectError {
+    /// samp hasn't created its network yet.
+    NotInitialized,
+    InvalidHost,
+    InvalidPort,
+    /// 3 to 24 characters of `0-9 a-z A-Z [ ] ( ) $ @ . _ =`.
+    InvalidNickname,
+    /// The nickname couldn't be written into samp's memory.
+    NicknameNotWritten,
+    /// `CNetGame::ShutdownForRestart` isn't where it's expected in this samp build.
+    RestartNotFound,
+}
+
+impl std::fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ConnectError::NotInitialized => "samp network isn't initialized",
+            ConnectError::InvalidHost => "invalid server host",
+            ConnectError::InvalidPort => "invalid server port",
+            ConnectError::InvalidNickname => "invalid nickname",
+            ConnectError::NicknameNotWritten => "failed to write the nickname",
+            ConnectError::RestartNotFound => "samp's restart function wasn't found",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ConnectError {}
+
+pub fn is_valid_nickname(nickname: &str) -> bool {
+    let valid_char = |ch: char| ch.is_ascii_alphanumeric() || "[]()$@._=".contains(ch);
+
+    (MIN_NICKNAME_LENGTH..=MAX_NICKNAME_LENGTH).contains(&nickname.len())
+        && nickname.chars().all(valid_char)
+}
+
+/// `ShowPlayerMarkers` mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerMarkersMode {
+    Off,
+    Global,
+    Streamed,
+    Unknown(i32),
+}
+
+impl From<i32> for PlayerMarkersMode {
+    fn from(mode: i32) -> PlayerMarkersMode {
+        match mode {
+            0 => PlayerMarkersMode::Off,
+            1 => PlayerMarkersMode::Global,
+            2 => PlayerMarkersMode::Streamed,
+            _ => PlayerMarkersMode::Unknown(mode),
+        }
+    }
+}
+
+/// `lagcompmode` of the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LagCompMode {
+    Off,
+    On,
+    /// Hits are lag compensated, only the position is checked.
+    PositionOnly,
+    Unknown(u8),
+}
+
+impl From<u8> for LagCompMode {
+    fn from(mode: u8) -> LagCompMode {
+        match mode {
+            0 => LagCompMode::Off,
+            1 => LagCompMode::On,
+            2 => LagCompMode::PositionOnly,
+            _ => LagCompMode::Unknown(mode),
+        }
+    }
+}
+
+/// Copy of the settings the server sent with `InitGame`, some are changed later by
+/// scripts (world time, weather, gravity).
+///
+/// samp hands the stunt bonus over to GTA and drops lag compensation, those two are only
+/// known once `InitGame` was passed to `NetGame::record_init_game`.
+///
+/// There is no stream distance: the server never sends it and streams everything itself.
+/// The only draw radii samp keeps are `name_tag_draw_distance` and `chat_radius`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServerSettings {
+    /// `ShowNameTags`.
+    pub name_tags: bool,
+    pub name_tag_draw_distance: f32,
+    /// Name tags are hidden behind walls.
+    pub name_tag_los: bool,
+    pub player_markers: PlayerMarkersMode,
+    /// `LimitGlobalChatRadius` is in use, `chat_radius` means nothing otherwise.
+    pub limit_chat_radius: bool,
+    pub chat_radius: f32,
+    /// Hour and minute.
+    pub world_time: (u8, u8),
+    pub weather: u8,
+    pub gravity: f32,
+    pub enter_exits: bool,
+    pub zone_names: bool,
+    pub cj_walk: bool,
+    pub manual_engine_and_lights: bool,
+    pub instagib: bool,
+    pub death_drop_money: u32,
+    /// `SetWorldBounds` values: max x, min x, max y, min y.
+    pub world_boundaries: [f32; 4],
+    /// `EnableStuntBonusForAll`, see `NetGame::record_init_game`.
+    pub stunt_bonus: Option<bool>,
+    /// See `NetGame::record_init_game`.
+    pub lag_compensation: Option<LagCompMode>,
+}
+
+/// `CNetGame::ShutdownForRestart`. The offsets are the ones `on_reconnect` has always
+/// hooked and weren't checked against every samp build, so the address has to look like
+/// the start of a function before it's called or hooked.
+fn shutdown_for_restart_address() -> Option<*mut u8> {
+    let address = match version() {
+        Version::V037 => v037::CNETGAME_SHUTDOWNFORRESTART,
+        Version::V037R3 => v037r3::CNETGAME_SHUTDOWNFORRESTART,
+        Version::V03DLR1 => v03dlr1::CNETGAME_SHUTDOWNFORRESTART,
+        _ => return None,
+    };
+
+    let ptr = unsafe { super::handle().add(address) };
+
+    if crate::utils::is_function_start(ptr) {
+        Some(ptr)
+    } else {
+        None
+    }
+}
+
+/// What `InitGame` carries and samp doesn't store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InitGameSettings {
+    stunt_bonus: bool,
+    lag_compensation: LagCompMode,
+}
+
+static INIT_GAME_SETTINGS: Mutex<Option<InitGameSettings>> = Mutex::new(None);
+
+fn parse_init_game(reader: &mut BitReader) -> Option<InitGameSettings> {
+    // zone names, ped anims, interior weapons, limit chat radius and its radius
+    reader.skip(4)?;
+    reader.read_f32()?;
+
+    let stunt_bonus = reader.read_bit()?;
+
+    // name tag distance, interior enter exits, name tag los, manual engine
+    reader.read_f32()?;
+    reader.skip(3)?;
+    // spawn count, player id, name tags, player markers
+    reader.read_u32()?;
+    reader.read_u16()?;
+    reader.skip(1)?;
+    reader.read_u32()?;
+    // world time, weather, gravity, lan mode, death drop, instagib
+    reader.read_u8()?;
+    reader.read_u8()?;
+    reader.read_f32()?;
+    reader.skip(1)?;
+    reader.read_u32()?;
+    reader.skip(1)?;
+    // on foot, in car and weapon send rates, multiplier
+    for _ in 0..4 {
+        reader.read_u32()?;
+    }
+
+    let lag_compensation = LagCompMode::from(reader.read_u8()?);
+
+    // hostname, vehicle models, vehicle friendly fire
+    let hostname_len = reader.read_u8()? as usize;
+    reader.skip(hostname_len * 8)?;
+    reader.skip(212 * 8)?;
+    reader.read_u32()?;
+
+    Some(InitGameSettings {
+        stunt_bonus,
+        lag_compensation,
+    })
+}
+
+/// Snapshot of the connection and the server the game is connected to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerInfo {
+    pub address: Option<SocketAddr>,
+    pub hostname: String,
+    pub lan_mode: bool,
+    /// Connected players including the local one.
+    pub player_count: usize,
+    /// `GetTickCount` value of the last connection attempt.
+    pub connect_tick: u32,
+    pub nametag_status: bool,
+    pub settings: Option<ServerSettings>,
+}
+
 pub struct NetGame<'a> {
     netgame_v1: Option<&'a mut v037::CNetGame>,
     netgame_v3: Option<&'a mut v037r3::CNetGame>,
@@ -46,26 +257,340 @@ impl<'a> NetGame<'a> {
         }
     }
 
+    /// `RakClientInterface*` of the connection, null before samp creates it.
+    pub fn rakclient(&self) -> *mut () {
+        if let Some(netgame) = self.netgame_v1.as_ref() {
+            return netgame.rakclient;
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_ref() {
+            return netgame.m_pRakClient;
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            return netgame.m_pRakClient;
+        }
+
+        std::ptr::null_mut()
+    }
+
+    pub fn settings(&self) -> Option<ServerSettings> {
+        let settings = if let Some(netgame) = self.netgame_v1.as_ref() {
+            netgame.settings()
+        } else if let Some(netgame) = self.netgame_v3.as_ref() {
+            netgame.settings()
+        } else if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            netgame.settings()
+        } else {
+            None
+        };
+
+        let init_game = *INIT_GAME_SETTINGS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        settings.map(|mut settings| {
+            if let Some(init_game) = init_game {
+                settings.stunt_bonus = Some(init_game.stunt_bonus);
+                settings.lag_compensation = Some(init_game.lag_compensation);
+            }
+
+            settings
+        })
+    }
+
+    /// Keeps the settings samp throws away from an `InitGame` payload (`RpcParameters::data`
+    /// of RPC `RPC_INIT_GAME`), see `ServerSettings`. The crate doesn't hook samp's RPC
+    /// handlers itself, so this is for callers that already see incoming RPCs. Returns
+    /// `false` if the payload isn't a complete `InitGame`.
+    pub fn record_init_game(data: &[u8], bit_length: usize) -> bool {
+        let settings = parse_init_game(&mut BitReader::new(data, bit_length));
+
+        if settings.is_some() {
+            *INIT_GAME_SETTINGS
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = settings;
+        }
+
+        settings.is_some()
+    }
+
+    /// Server name from `InitGame`, empty while connecting.
+    pub fn hostname(&self) -> String {
+        if let Some(netgame) = self.netgame_v1.as_ref() {
+            return netgame.hostname();
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_ref() {
+            return netgame.hostname();
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            return netgame.hostname();
+        }
+
+        String::new()
+    }
+
+    pub fn lan_mode(&self) -> bool {
+        if let Some(netgame) = self.netgame_v1.as_ref() {
+            return netgame.lanmode != 0;
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_ref() {
+            return netgame.m_bLanMode != 0;
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            return netgame.m_bLanMode != 0;
+        }
+
+        false
+    }
+
+    /// `SetPlayerMapIcon` slots in use, as (icon id, GTA blip handle).
+    pub fn map_icons(&self) -> Vec<(u8, i32)> {
+        let icons: Vec<i32> = if let Some(netgame) = self.netgame_v1.as_ref() {
+            { netgame.map_icons }.iter().map(|&icon| icon as i32).collect()
+        } else if let Some(netgame) = self.netgame_v3.as_ref() {
+            { netgame.m_aMapIcons }.to_vec()
+        } else if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            { netgame.m_aMapIcons }.to_vec()
+        } else {
+            Vec::new()
+        };
+
+        icons
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, handle)| handle != 0)
+            .map(|(id, handle)| (id as u8, handle))
+            .collect()
+    }
+
+    pub fn connect_tick(&self) -> u32 {
+        if let Some(netgame) = self.netgame_v1.as_ref() {
+            return netgame.connect_tick;
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_ref() {
+            return netgame.m_lastConnectAttempt as u32;
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            return netgame.m_lastConnectAttempt as u32;
+        }
+
+        0
+    }
+
+    /// Restarts samp's wait before it tries to connect again on its own.
+    pub fn postpone_connect(&mut self) {
+        let now = crate::utils::tick_count();
+
+        if let Some(netgame) = self.netgame_v1.as_mut() {
+            netgame.connect_tick = now;
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_mut() {
+            netgame.m_lastConnectAttempt = now as _;
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_mut() {
+            netgame.m_lastConnectAttempt = now as _;
+        }
+    }
+
+    /// Name tags toggled by the server at runtime, `ServerSettings::name_tags` is the
+    /// `InitGame` value.
+    pub fn nametag_status(&self) -> bool {
+        if let Some(netgame) = self.netgame_v1.as_ref() {
+            return netgame.nametag_status;
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_ref() {
+            return netgame.m_bNametagStatus;
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_ref() {
+            return netgame.m_bNametagStatus;
+        }
+
+        false
+    }
+
+    /// Connected players including the local one, 0 before the player pool exists.
+    pub fn player_count(&self) -> usize {
+        let remote = match version() {
+            Version::V037 => v037::players().map(|players| players.count()),
+            Version::V037R3 => v037r3::players().map(|players| players.count()),
+            Version::V03DLR1 => v03dlr1::players().map(|players| players.count()),
+            _ => None,
+        };
+
+        remote.map(|count| count + 1).unwrap_or(0)
+    }
+
+    pub fn server_info(&self) -> ServerInfo {
+        ServerInfo {
+            address: self.addr(),
+            hostname: self.hostname(),
+            lan_mode: self.lan_mode(),
+            player_count: self.player_count(),
+            connect_tick: self.connect_tick(),
+            nametag_status: self.nametag_status(),
+            settings: self.settings(),
+        }
+    }
+
+    /// Drops the connection like `/q` does, without closing the game.
+    pub fn disconnect(&self) {
+        let rakclient = self.rakclient();
+
+        if rakclient.is_null() {
+            return;
+        }
+
+        unsafe {
+            let vtable = *(rakclient as *const *const usize);
+            let disconnect: extern "thiscall" fn(*mut (), std::os::raw::c_uint, u8) =
+                std::mem::transmute(*vtable.add(DISCONNECT_VTABLE_INDEX));
+
+            disconnect(rakclient, DISCONNECT_BLOCK_DURATION, 0);
+        }
+    }
+
+    #[deprecated(note = "use `disconnect`")]
     pub fn quit_game(&self) {
-        let address = match version() {
-            Version::V037 => 0x68270, //hz
-            Version::V037R3 => 0x68270, 
-            Version::V03DLR1 => 0x68270, //hz
-            _ => return,
+        self.disconnect();
+    }
+
+    /// Disconnects and connects to the same server again, dropping everything the server
+    /// created like a `gmx` does.
+    ///
+    /// It goes through `CNetGame::ShutdownForRestart`, so callbacks given to
+    /// `on_reconnect` run too, don't reconnect from one of them. Returns `false` and
+    /// does nothing when that function isn't found.
+    pub fn reconnect(&mut self) -> bool {
+        if shutdown_for_restart_address().is_none() {
+            return false;
+        }
+
+        self.disconnect();
+        self.shutdown_for_restart();
+        self.wait_connect();
+
+        true
+    }
+
+    /// Switches to another server. The nickname and password are used for this and
+    /// following connections.
+    pub fn connect(
+        &mut self,
+        host: &str,
+        port: u16,
+        nickname: &str,
+        password: &str,
+    ) -> Result<(), ConnectError> {
+        if self.rakclient().is_null() {
+            return Err(ConnectError::NotInitialized);
+        }
+
+        if host.is_empty() || host.len() >= MAX_HOST_LENGTH || !host.is_ascii() {
+            return Err(ConnectError::InvalidHost);
+        }
+
+        if port == 0 {
+            return Err(ConnectError::InvalidPort);
+        }
+
+        if !is_valid_nickname(nickname) {
+            return Err(ConnectError::InvalidNickname);
+        }
+
+        if shutdown_for_restart_address().is_none() {
+            return Err(ConnectError::RestartNotFound);
+        }
+
+        let password = super::encoding::encode_c_string(password);
+
+        if !super::players::set_local_player_name(nickname) {
+            return Err(ConnectError::NicknameNotWritten);
+        }
+
+        self.disconnect();
+        self.set_password(&password);
+        self.shutdown_for_restart();
+
+        if let Some(netgame) = self.netgame_v1.as_mut() {
+            netgame.set_server(host.as_bytes(), port);
+            netgame.wait_connect();
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_mut() {
+            netgame.set_server(host.as_bytes(), port);
+            netgame.wait_connect();
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_mut() {
+            netgame.set_server(host.as_bytes(), port);
+            netgame.wait_connect();
+        }
+
+        Ok(())
+    }
+
+    fn set_password(&self, password: &[u8]) {
+        let rakclient = self.rakclient();
+
+        if rakclient.is_null() {
+            return;
+        }
+
+        unsafe {
+            let vtable = *(rakclient as *const *const usize);
+            let set_password: extern "thiscall" fn(*mut (), *const u8) =
+                std::mem::transmute(*vtable.add(SET_PASSWORD_VTABLE_INDEX));
+
+            set_password(rakclient, password.as_ptr());
+        }
+    }
+
+    /// Deletes the pools and resets the world, the connection is left as it is.
+    fn shutdown_for_restart(&mut self) {
+        let ptr = match shutdown_for_restart_address() {
+            Some(ptr) => ptr,
+            None => return,
+        };
+
+        let netgame = if let Some(netgame) = self.netgame_v1.as_mut() {
+            *netgame as *mut _ as *mut ()
+        } else if let Some(netgame) = self.netgame_v3.as_mut() {
+            *netgame as *mut _ as *mut ()
+        } else if let Some(netgame) = self.netgame_v03dl.as_mut() {
+            *netgame as *mut _ as *mut ()
+        } else {
+            return;
         };
 
         unsafe {
-            let ptr = super::handle().add(address);
-            let quit_func: extern "thiscall" fn(*mut ()) = std::mem::transmute(ptr);
-            
-            match version() {
-                Version::V037R3 => {
-                    if let Some(netgame) = self.netgame_v3.as_ref() {
-                        quit_func(netgame as *const _ as *mut ());
-                    }
-                },
-                _ => {}
-            }
+            let shutdown: extern "thiscall" fn(*mut ()) = std::mem::transmute(ptr);
+
+            shutdown(netgame);
+        }
+    }
+
+    fn wait_connect(&mut self) {
+        if let Some(netgame) = self.netgame_v1.as_mut() {
+            netgame.wait_connect();
+        }
+
+        if let Some(netgame) = self.netgame_v3.as_mut() {
+            netgame.wait_connect();
+        }
+
+        if let Some(netgame) = self.netgame_v03dl.as_mut() {
+            netgame.wait_connect();
         }
     }
 
@@ -93,16 +618,15 @@ impl<'a> NetGame<'a> {
         }
     }
 
+    /// Called after samp restarts the game, on `gmx`, after losing the connection and
+    /// on `reconnect`/`connect`.
     pub fn on_reconnect<F: FnMut() + 'static>(callback: F) {
-        let address = match version() {
-            Version::V037 => 0xA060,
-            Version::V037R3 => 0xA1E0,
-            Version::V03DLR1 => 0xA230,
-            _ => return,
+        let ptr = match shutdown_for_restart_address() {
+            Some(ptr) => ptr,
+            None => return,
         };
 
         unsafe {
-            let ptr = super::handle().add(address);
             let func: extern "thiscall" fn(*mut ()) = std::mem::transmute(ptr);
 
             let _ = GenericDetour::new(func, cnetgame_reconnect)
@@ -388,4 +912,115 @@ extern "thiscall" fn cnetgame_connection_rejected(this: *mut ()) {
             (hook.callback)();     // Затем колбэк
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct BitWriter {
+        bytes: Vec<u8>,
+        bits: usize,
+    }
+
+    impl BitWriter {
+        fn bit(&mut self, bit: bool) -> &mut Self {
+            if self.bits % 8 == 0 {
+                self.bytes.push(0);
+            }
+
+            if bit {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.bits % 8);
+            }
+
+            self.bits += 1;
+            self
+        }
+
+        fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+            for byte in bytes {
+                for shift in (0..8).rev() {
+                    self.bit(byte >> shift & 1 != 0);
+                }
+            }
+
+            self
+        }
+    }
+
+    fn init_game(stunt_bonus: bool, lag_compensation: u8) -> BitWriter {
+        let mut writer = BitWriter::default();
+
+        writer
+            .bit(true)
+            .bit(false)
+            .bit(true)
+            .bit(true)
+            .bytes(&200.0f32.to_le_bytes())
+            .bit(stunt_bonus)
+            .bytes(&70.0f32.to_le_bytes())
+            .bit(false)
+            .bit(true)
+            .bit(false)
+            .bytes(&3u32.to_le_bytes())
+            .bytes(&42u16.to_le_bytes())
+            .bit(true)
+            .bytes(&2u32.to_le_bytes())
+            .bytes(&[12, 10])
+            .bytes(&0.008f32.to_le_bytes())
+            .bit(false)
+            .bytes(&0u32.to_le_bytes())
+            .bit(false)
+            .bytes(&40u32.to_le_bytes())
+            .bytes(&40u32.to_le_bytes())
+            .bytes(&40u32.to_le_bytes())
+            .bytes(&2u32.to_le_bytes())
+            .bytes(&[lag_compensation])
+            .bytes(&[4])
+            .bytes(b"test")
+            .bytes(&[1; 212])
+            .bytes(&1u32.to_le_bytes());
+
+        writer
+    }
+
+    fn parse(writer: &BitWriter) -> Option<InitGameSettings> {
+        parse_init_game(&mut BitReader::new(&writer.bytes, writer.bits))
+    }
+
+    #[test]
+    fn init_game_settings() {
+        let cases = [
+            (true, 1, LagCompMode::On),
+            (false, 0, LagCompMode::Off),
+            (true, 2, LagCompMode::PositionOnly),
+            (false, 7, LagCompMode::Unknown(7)),
+        ];
+
+        for (stunt_bonus, mode, lag_compensation) in cases {
+            let expected = InitGameSettings {
+                stunt_bonus,
+                lag_compensation,
+            };
+
+            assert_eq!(parse(&init_game(stunt_bonus, mode)), Some(expected));
+        }
+    }
+
+    #[test]
+    fn truncated_init_game() {
+        let full = init_game(true, 1);
+        // inside the lag compensation mode, the hostname, the vehicle models and the last field
+        for cut in [32 + 212 * 8 + 5 * 8 + 4, 32 + 212 * 8 + 16, 32 + 100, 1] {
+            let mut writer = BitWriter {
+                bytes: full.bytes.clone(),
+                bits: full.bits - cut,
+            };
+            writer.bytes.truncate(writer.bits.div_ceil(8));
+
+            assert_eq!(parse(&writer), None);
+        }
+
+        assert_eq!(parse(&BitWriter::default()), None);
+    }
+}
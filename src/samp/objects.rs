@@ -3,6 +3,7 @@ use super::version::{Version, version};
 
 use crate::gta::object::CObject;
 use crate::gta::matrix::CVector;
+use crate::gta::weapons::{weapon_by_model, Weapon};
 
 pub struct Object<'a> {
     object_v1: Option<&'a r1::CObject>,
@@ -65,6 +66,19 @@ impl<'a> Object<'a> {
             .unwrap_or_else(|| CVector::zero())
     }
 
+    pub fn model(&self) -> i32 {
+        let v1 = self.object_v1.map(|obj| obj.m_nModel);
+        let v3 = self.object_v3.map(|obj| obj.m_nModel);
+        let dl = self.object_dl.map(|obj| obj.m_nModel);
+
+        dl.or(v3).or(v1).unwrap_or(-1)
+    }
+
+    /// Weapon this object is a world model of (pickups, attached guns).
+    pub fn weapon(&self) -> Option<&'static Weapon> {
+        weapon_by_model(self.model())
+    }
+
     pub fn get(object_id: i32) -> Option<Object<'a>> {
         match version() {
             Version::V037 => r1::find_object(object_id).map(|obj| Object::new_v1(obj)),
@@ -65,10 +65,147 @@ pub struct AimData {
     pub m_aimf1: CVector,
     pub m_aimPos: CVector,
     pub m_fAimZ: f32,
-    pub _bitfield_1: u8,
+    pub _bitfield_1: u8, // m_nCameraExtZoom : 6, m_nWeaponState : 2
     pub m_nAspectRatio: std::os::raw::c_char,
 }
 
+impl AimData {
+    pub fn camera_mode(&self) -> CameraMode {
+        CameraMode::from(self.m_nCameraMode)
+    }
+
+    pub fn weapon_state(&self) -> WeaponState {
+        WeaponState::from(self._bitfield_1 >> 6)
+    }
+
+    /// Raw 6-bit camera zoom as it is sent over the network.
+    pub fn camera_zoom_raw(&self) -> u8 {
+        self._bitfield_1 & 0x3F
+    }
+
+    /// Camera field of view in degrees (35.0 ..= 70.0).
+    pub fn camera_zoom(&self) -> f32 {
+        self.camera_zoom_raw() as f32 * 35.0 / 63.0 + 35.0
+    }
+
+    pub fn aspect_ratio(&self) -> f32 {
+        self.m_nAspectRatio as u8 as f32 / 255.0 + 1.0
+    }
+
+    pub fn aim_front(&self) -> CVector {
+        {self.m_aimf1}.normalized()
+    }
+
+    pub fn aim_position(&self) -> CVector {
+        self.m_aimPos
+    }
+
+    pub fn aim_z(&self) -> f32 {
+        self.m_fAimZ
+    }
+}
+
+// eCamMode values that show up in the aim sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    FollowPed,
+    Sniper,
+    RocketLauncher,
+    Fixed,
+    FirstPerson,
+    CamOnAString,
+    BehindBoat,
+    M16FirstPerson,
+    HeliCannon,
+    Camera,
+    RocketLauncherHeatSeek,
+    AimWeapon,
+    AimWeaponFromCar,
+    AimWeaponAttached,
+    Other(u8),
+}
+
+impl CameraMode {
+    /// Modes where the player looks through a scope or over the shoulder.
+    pub fn is_aiming(&self) -> bool {
+        match self {
+            CameraMode::Sniper
+            | CameraMode::RocketLauncher
+            | CameraMode::M16FirstPerson
+            | CameraMode::HeliCannon
+            | CameraMode::Camera
+            | CameraMode::RocketLauncherHeatSeek
+            | CameraMode::AimWeapon
+            | CameraMode::AimWeaponFromCar
+            | CameraMode::AimWeaponAttached => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<u8> for CameraMode {
+    fn from(mode: u8) -> CameraMode {
+        match mode {
+            4 => CameraMode::FollowPed,
+            7 => CameraMode::Sniper,
+            8 => CameraMode::RocketLauncher,
+            15 => CameraMode::Fixed,
+            16 => CameraMode::FirstPerson,
+            18 => CameraMode::CamOnAString,
+            22 => CameraMode::BehindBoat,
+            34 => CameraMode::M16FirstPerson,
+            45 => CameraMode::HeliCannon,
+            46 => CameraMode::Camera,
+            51 => CameraMode::RocketLauncherHeatSeek,
+            53 => CameraMode::AimWeapon,
+            55 => CameraMode::AimWeaponFromCar,
+            65 => CameraMode::AimWeaponAttached,
+            mode => CameraMode::Other(mode),
+        }
+    }
+}
+
+impl From<CameraMode> for u8 {
+    fn from(mode: CameraMode) -> u8 {
+        match mode {
+            CameraMode::FollowPed => 4,
+            CameraMode::Sniper => 7,
+            CameraMode::RocketLauncher => 8,
+            CameraMode::Fixed => 15,
+            CameraMode::FirstPerson => 16,
+            CameraMode::CamOnAString => 18,
+            CameraMode::BehindBoat => 22,
+            CameraMode::M16FirstPerson => 34,
+            CameraMode::HeliCannon => 45,
+            CameraMode::Camera => 46,
+            CameraMode::RocketLauncherHeatSeek => 51,
+            CameraMode::AimWeapon => 53,
+            CameraMode::AimWeaponFromCar => 55,
+            CameraMode::AimWeaponAttached => 65,
+            CameraMode::Other(mode) => mode,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponState {
+    NoBullets,
+    LastBullet,
+    MoreBullets,
+    Reloading,
+}
+
+impl From<u8> for WeaponState {
+    fn from(state: u8) -> WeaponState {
+        match state & 0b11 {
+            0 => WeaponState::NoBullets,
+            1 => WeaponState::LastBullet,
+            2 => WeaponState::MoreBullets,
+            _ => WeaponState::Reloading,
+        }
+    }
+}
+
 #[repr(C, packed)]
 pub struct ControllerState {
     pub m_sLeftStickX: std::os::raw::c_short,
@@ -0,0 +1,103 @@
+use retour::GenericDetour;
+
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::matrix::CVector;
+use crate::utils::distance;
+
+pub const MAX_PICKUPS: usize = 4096;
+
+/// Copy of a server pickup.
+#[derive(Debug, Clone, Copy)]
+pub struct Pickup {
+    pub id: i32,
+    pub model: i32,
+    /// `CreatePickup` type, e.g. 1 (always there), 2 (respawns) or 19 (one shot).
+    pub pickup_type: i32,
+    pub position: CVector,
+    /// GTA pickup handle used by the scripting opcodes.
+    pub handle: i32,
+}
+
+struct SendNotificationHook {
+    hook: GenericDetour<extern "thiscall" fn(*mut (), std::os::raw::c_int)>,
+    callback: Box<dyn FnMut(&Pickup)>,
+}
+
+static mut SEND_NOTIFICATION_HOOK: Option<SendNotificationHook> = None;
+
+extern "thiscall" fn send_notification(this: *mut (), pickup_id: std::os::raw::c_int) {
+    unsafe {
+        if let Some(hook) = SEND_NOTIFICATION_HOOK.as_mut() {
+            if let Some(pickup) = pickup(pickup_id) {
+                (hook.callback)(&pickup);
+            }
+
+            hook.hook.call(this, pickup_id);
+        }
+    }
+}
+
+/// Every pickup the server has created for the player.
+pub fn pickups() -> Vec<Pickup> {
+    let pickups = match version() {
+        Version::V037 => r1::pickup_pool()
+            .map(|pool| (0..MAX_PICKUPS).filter_map(|id| pool.pickup(id)).collect()),
+        Version::V037R3 => r3::pickup_pool()
+            .map(|pool| (0..MAX_PICKUPS).filter_map(|id| pool.pickup(id)).collect()),
+        Version::V03DLR1 => dl::pickup_pool()
+            .map(|pool| (0..MAX_PICKUPS).filter_map(|id| pool.pickup(id)).collect()),
+        _ => None,
+    };
+
+    pickups.unwrap_or_else(Vec::new)
+}
+
+pub fn pickup(pickup_id: i32) -> Option<Pickup> {
+    if pickup_id < 0 {
+        return None;
+    }
+
+    let id = pickup_id as usize;
+
+    match version() {
+        Version::V037 => r1::pickup_pool().and_then(|pool| pool.pickup(id)),
+        Version::V037R3 => r3::pickup_pool().and_then(|pool| pool.pickup(id)),
+        Version::V03DLR1 => dl::pickup_pool().and_then(|pool| pool.pickup(id)),
+        _ => None,
+    }
+}
+
+pub fn nearest_pickup(point: &CVector) -> Option<Pickup> {
+    pickups().into_iter().min_by(|a, b| {
+        let a = distance(&a.position, point);
+        let b = distance(&b.position, point);
+
+        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+    })
+}
+
+/// Called when samp is about to tell the server that the local player picked a pickup up.
+pub fn on_pickup<F: FnMut(&Pickup) + 'static>(callback: F) {
+    let address = match version() {
+        Version::V037 => r1::CPICKUPPOOL_SENDNOTIFICATION,
+        Version::V037R3 => r3::CPICKUPPOOL_SENDNOTIFICATION,
+        Version::V03DLR1 => dl::CPICKUPPOOL_SENDNOTIFICATION,
+        _ => return,
+    };
+
+    unsafe {
+        let ptr = super::handle().add(address);
+        let func: extern "thiscall" fn(*mut (), std::os::raw::c_int) = std::mem::transmute(ptr);
+
+        if let Ok(hook) = GenericDetour::new(func, send_notification) {
+            let _ = hook.enable();
+
+            SEND_NOTIFICATION_HOOK = Some(SendNotificationHook {
+                hook,
+                callback: Box::new(callback),
+            });
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use super::version::{version, Version};
+use super::damage::VehicleDamage;
+use super::packets::AimData;
+use super::{encoding, GameAllocator};
 use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+use crate::gta::entity::CEntity;
 use crate::gta::matrix::{CVector, RwMatrix};
+use crate::gta::skins::{skin_info, Skin};
+use crate::gta::weapons::{weapon_info, Weapon};
 
 #[repr(C, packed)]
 pub struct GamePed {
@@ -8,6 +14,23 @@ pub struct GamePed {
     pub matrix: *mut RwMatrix,
 }
 
+impl GamePed {
+    pub fn model_index(&self) -> u16 {
+        let entity = self as *const GamePed as *const CEntity;
+        unsafe { (*entity).m_nModelIndex }
+    }
+
+    /// `CPed::m_fHealth`.
+    pub fn health(&self) -> f32 {
+        unsafe { (self as *const GamePed as *const u8).add(0x540).cast::<f32>().read_unaligned() }
+    }
+
+    /// `CPed::m_fCurrentRotation` in radians.
+    pub fn rotation(&self) -> f32 {
+        unsafe { (self as *const GamePed as *const u8).add(0x558).cast::<f32>().read_unaligned() }
+    }
+}
+
 pub struct LocalPlayer<'a> {
     player_v1: Option<&'a mut r1::CLocalPlayer>,
     player_v3: Option<&'a mut r3::CLocalPlayer>,
@@ -95,6 +118,39 @@ impl<'a> LocalPlayer<'a> {
         None
     }
 
+    pub fn aim_data(&self) -> Option<&AimData> {
+        if let Some(player) = self.player_v1.as_ref() {
+            return Some(&player.m_aimData);
+        }
+
+        if let Some(player) = self.player_v3.as_ref() {
+            return Some(&player.m_aimData);
+        }
+
+        if let Some(player) = self.player_dl.as_ref() {
+            return Some(&player.m_aimData);
+        }
+
+        None
+    }
+
+    /// Damage of the vehicle the local player is driving, as last synced to the server.
+    pub fn vehicle_damage(&self) -> Option<VehicleDamage> {
+        if let Some(player) = self.player_v1.as_ref() {
+            return Some(player.m_damage.damage());
+        }
+
+        if let Some(player) = self.player_v3.as_ref() {
+            return Some(player.m_damage.damage());
+        }
+
+        if let Some(player) = self.player_dl.as_ref() {
+            return Some(player.m_damage.damage());
+        }
+
+        None
+    }
+
     pub fn ped(&self) -> *const () {
         if let Some(player) = self.player_v1.as_ref() {
             return unsafe { (*player.m_pPed).m_pGamePed as *mut _ };
@@ -110,6 +166,41 @@ impl<'a> LocalPlayer<'a> {
 
         std::ptr::null()
     }
+
+    pub fn skin(&self) -> Option<u16> {
+        let ped = self.ped() as *const GamePed;
+
+        if ped.is_null() {
+            None
+        } else {
+            Some(unsafe { (*ped).model_index() })
+        }
+    }
+
+    pub fn skin_info(&self) -> Option<&'static Skin> {
+        self.skin().and_then(|skin| skin_info(skin as i32))
+    }
+
+    pub fn current_weapon(&self) -> Option<u8> {
+        if let Some(player) = self.player_v1.as_ref() {
+            return Some(player.current_weapon());
+        }
+
+        if let Some(player) = self.player_v3.as_ref() {
+            return Some(player.current_weapon());
+        }
+
+        if let Some(player) = self.player_dl.as_ref() {
+            return Some(player.current_weapon());
+        }
+
+        None
+    }
+
+    pub fn weapon(&self) -> Option<&'static Weapon> {
+        self.current_weapon()
+            .and_then(|weapon| weapon_info(weapon as i32))
+    }
 }
 
 #[allow(unused)]
@@ -183,6 +274,18 @@ impl<'a> Player<'a> {
         None
     }
 
+    pub fn skin(&self) -> Option<u16> {
+        self.gta_ped().map(|ped| ped.model_index())
+    }
+
+    pub fn skin_info(&self) -> Option<&'static Skin> {
+        self.skin().and_then(|skin| skin_info(skin as i32))
+    }
+
+    pub fn weapon(&self) -> Option<&'static Weapon> {
+        self.remote_player().and_then(|remote| remote.weapon())
+    }
+
     pub fn is_in_stream(&self) -> bool {
         if let Some(player) = self.player_v1.as_ref() {
             return player.is_in_stream();
@@ -319,6 +422,42 @@ impl<'a> RemotePlayer<'a> {
         CVector::zero()
     }
 
+    pub fn current_weapon(&self) -> u8 {
+        if let Some(remote) = self.remote_v1.as_ref() {
+            return remote.current_weapon();
+        }
+
+        if let Some(remote) = self.remote_v3.as_ref() {
+            return remote.current_weapon();
+        }
+
+        if let Some(remote) = self.remote_dl.as_ref() {
+            return remote.current_weapon();
+        }
+
+        0
+    }
+
+    pub fn weapon(&self) -> Option<&'static Weapon> {
+        weapon_info(self.current_weapon() as i32)
+    }
+
+    pub fn aim_data(&self) -> Option<&AimData> {
+        if let Some(remote) = self.remote_v1.as_ref() {
+            return Some(&remote.m_aimData);
+        }
+
+        if let Some(remote) = self.remote_v3.as_ref() {
+            return Some(&remote.m_aimData);
+        }
+
+        if let Some(remote) = self.remote_dl.as_ref() {
+            return Some(&remote.m_aimData);
+        }
+
+        None
+    }
+
     pub fn id(&self) -> u16 {
         if let Some(remote) = self.remote_v1.as_ref() {
             return remote.id();
@@ -357,6 +496,21 @@ pub fn local_player<'a>() -> Option<LocalPlayer<'a>> {
     }
 }
 
+/// Writes the local nickname into samp's memory, samp sends it on the next connection.
+/// Without samp's allocator (see `GameAllocator::samp`) only a name of up to 15 bytes
+/// can be written, and only while samp keeps the current one inline.
+pub fn set_local_player_name(name: &str) -> bool {
+    let allocator = GameAllocator::samp();
+    let name = encoding::encode(name);
+
+    match version() {
+        Version::V037 => r1::set_local_player_name(&name, allocator),
+        Version::V037R3 => r3::set_local_player_name(&name, allocator),
+        Version::V03DLR1 => dl::set_local_player_name(&name, allocator),
+        _ => false,
+    }
+}
+
 pub fn find_player<'a>(id: i32) -> Option<Player<'a>> {
     match version() {
         Version::V037 => Some(Player {
@@ -378,6 +532,29 @@ pub fn find_player<'a>(id: i32) -> Option<Player<'a>> {
     }
 }
 
+/// Ids and nicknames of everyone in the player pool, the local player included.
+pub fn player_names() -> Vec<(u16, String)> {
+    let mut names: Vec<(u16, String)> = players()
+        .map(|players| {
+            players
+                .filter_map(|player| {
+                    let id = player.remote_player()?.id();
+                    Some((id, player.name()?))
+                })
+                .collect()
+        })
+        .unwrap_or_else(Vec::new);
+
+    if let Some(player) = local_player() {
+        if let (Some(id), Some(name)) = (player.id(), player.name()) {
+            names.push((id as u16, name));
+        }
+    }
+
+    names.sort_by_key(|(id, _)| *id);
+    names
+}
+
 pub fn players<'a>() -> Option<PlayersIterator<'a>> {
     match version() {
         Version::V037 => Some(PlayersIterator {
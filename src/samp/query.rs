@@ -0,0 +1,112 @@
+// The SA-MP query protocol, the one the server browser uses to show player counts.
+
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::Duration;
+
+use super::encoding;
+
+const HEADER_LENGTH: usize = 11;
+const OPCODE_INFO: u8 = b'i';
+
+/// Answer to the `i` query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryInfo {
+    pub password: bool,
+    pub players: u16,
+    pub max_players: u16,
+    pub hostname: String,
+    pub gamemode: String,
+    pub language: String,
+}
+
+impl QueryInfo {
+    pub fn is_full(&self) -> bool {
+        self.players >= self.max_players
+    }
+}
+
+fn packet_header(addr: &SocketAddr, opcode: u8) -> Option<[u8; HEADER_LENGTH]> {
+    let ip = match addr {
+        SocketAddr::V4(addr) => addr.ip().octets(),
+        SocketAddr::V6(_) => return None,
+    };
+
+    let port = addr.port().to_le_bytes();
+
+    Some([b'S', b'A', b'M', b'P', ip[0], ip[1], ip[2], ip[3], port[0], port[1], opcode])
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take(2).map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        self.take(len).map(encoding::decode)
+    }
+}
+
+/// Parses the answer to an `i` query, header included.
+pub fn parse_info(response: &[u8]) -> Option<QueryInfo> {
+    if response.len() < HEADER_LENGTH
+        || &response[..4] != b"SAMP"
+        || response[HEADER_LENGTH - 1] != OPCODE_INFO
+    {
+        return None;
+    }
+
+    let mut reader = Reader {
+        bytes: &response[HEADER_LENGTH..],
+    };
+
+    Some(QueryInfo {
+        password: reader.u8()? != 0,
+        players: reader.u16()?,
+        max_players: reader.u16()?,
+        hostname: reader.string()?,
+        gamemode: reader.string()?,
+        language: reader.string()?,
+    })
+}
+
+/// Asks the server for its info. Blocks up to `timeout`, so don't call it from the game thread.
+pub fn query_info(addr: SocketAddr, timeout: Duration) -> io::Result<QueryInfo> {
+    let request = packet_header(&addr, OPCODE_INFO)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "only IPv4 is supported"))?;
+
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    socket.set_read_timeout(Some(timeout))?;
+    socket.connect(addr)?;
+    socket.send(&request)?;
+
+    let mut response = [0u8; 2048];
+    let len = socket.recv(&mut response)?;
+
+    parse_info(&response[..len])
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed query answer"))
+}
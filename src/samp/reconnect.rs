@@ -0,0 +1,663 @@
+// Auto-reconnect policy driven by the `NetGame` connection events.
+//
+// `ReconnectManager` is a plain state machine: it's fed events and the current time and
+// answers with what to do, so it runs the same against a fake `Clock`. `enable` and
+// `process` wire it to samp, holding samp's own retry while the manager waits. Only
+// 0.3.7 R3 is supported, `enable` fails with `ReconnectError` on R1 and DL R1.
+
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::netgame::NetGame;
+use super::query;
+use super::version::{version, Version};
+use super::Gamestate;
+
+pub trait Clock {
+    /// Time passed since some fixed point, it must never go back.
+    fn now(&self) -> Duration;
+}
+
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first attempt.
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// Delay growth per failed attempt.
+    pub multiplier: f32,
+    /// Random part of a delay, 0.25 spreads it over ±25%.
+    pub jitter: f32,
+    /// `None` retries forever.
+    pub max_attempts: Option<u32>,
+    /// On "server is full", poll the server with the query protocol and reconnect only
+    /// when a slot is free, instead of counting it as a failed attempt.
+    pub wait_while_full: bool,
+    pub full_poll_interval: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> ReconnectPolicy {
+        ReconnectPolicy {
+            initial_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.25,
+            max_attempts: Some(10),
+            wait_while_full: true,
+            full_poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    Connected,
+    ClosedConnection,
+    ServerFull,
+    WrongPassword,
+    Banned,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Banned,
+    WrongPassword,
+    MaxAttempts,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectState {
+    Connected,
+    /// Attempt number `attempt` is made at `at`.
+    Waiting { attempt: u32, at: Duration },
+    /// An attempt was made, waiting for the server to answer.
+    Connecting { attempt: u32 },
+    /// The server is full, it's queried at `next_query` unless a query is in flight.
+    WaitingForSlot {
+        attempt: u32,
+        next_query: Duration,
+        query_pending: bool,
+    },
+    Stopped(StopReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Reconnect,
+    /// Query the server and report with `handle_query_result`.
+    QueryServer,
+}
+
+pub struct ReconnectManager<C: Clock> {
+    policy: ReconnectPolicy,
+    clock: C,
+    state: ReconnectState,
+    rng: u64,
+}
+
+impl<C: Clock> ReconnectManager<C> {
+    pub fn new(policy: ReconnectPolicy, clock: C) -> ReconnectManager<C> {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or(0);
+
+        ReconnectManager::with_seed(policy, clock, seed)
+    }
+
+    /// Same jitter for the same seed.
+    pub fn with_seed(policy: ReconnectPolicy, clock: C, seed: u64) -> ReconnectManager<C> {
+        ReconnectManager {
+            policy,
+            clock,
+            state: ReconnectState::Connected,
+            rng: seed | 1,
+        }
+    }
+
+    pub fn state(&self) -> ReconnectState {
+        self.state
+    }
+
+    pub fn policy(&self) -> &ReconnectPolicy {
+        &self.policy
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Forgets failed attempts and a stop, e.g. after the user fixed the password.
+    pub fn reset(&mut self) {
+        self.state = ReconnectState::Connected;
+    }
+
+    pub fn handle_event(&mut self, event: ConnectionEvent) {
+        let attempt = self.attempt();
+
+        self.state = match (event, self.state) {
+            (ConnectionEvent::Connected, _) => ReconnectState::Connected,
+            (ConnectionEvent::Banned, _) => ReconnectState::Stopped(StopReason::Banned),
+            (ConnectionEvent::WrongPassword, _) => {
+                ReconnectState::Stopped(StopReason::WrongPassword)
+            }
+            (_, ReconnectState::Stopped(reason)) => ReconnectState::Stopped(reason),
+            // samp reports every failed attempt while the query decides, ignore them.
+            (_, state @ ReconnectState::WaitingForSlot { .. }) => state,
+            (ConnectionEvent::ServerFull, _) if self.policy.wait_while_full => {
+                ReconnectState::WaitingForSlot {
+                    attempt,
+                    next_query: self.clock.now() + self.policy.full_poll_interval,
+                    query_pending: false,
+                }
+            }
+            _ => self.schedule(attempt + 1),
+        };
+    }
+
+    /// Result of the query asked with `Action::QueryServer`, `None` when it failed.
+    pub fn handle_query_result(&mut self, info: Option<&query::QueryInfo>) {
+        if let ReconnectState::WaitingForSlot {
+            attempt,
+            next_query,
+            ..
+        } = self.state
+        {
+            self.state = match info {
+                Some(info) if !info.is_full() => ReconnectState::Waiting {
+                    attempt: attempt + 1,
+                    at: self.clock.now(),
+                },
+                _ => ReconnectState::WaitingForSlot {
+                    attempt,
+                    next_query,
+                    query_pending: false,
+                },
+            };
+        }
+    }
+
+    /// Call it regularly, it returns what has to be done now.
+    pub fn update(&mut self) -> Option<Action> {
+        let now = self.clock.now();
+
+        match self.state {
+            ReconnectState::Waiting { attempt, at } if now >= at => {
+                self.state = ReconnectState::Connecting { attempt };
+                Some(Action::Reconnect)
+            }
+
+            ReconnectState::WaitingForSlot {
+                attempt,
+                next_query,
+                query_pending: false,
+            } if now >= next_query => {
+                self.state = ReconnectState::WaitingForSlot {
+                    attempt,
+                    next_query: now + self.policy.full_poll_interval,
+                    query_pending: true,
+                };
+
+                Some(Action::QueryServer)
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Delay before attempt number `attempt` (starting at 1), jitter included.
+    pub fn delay(&mut self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let base = self.policy.initial_delay.as_secs_f32() * self.policy.multiplier.powi(exponent);
+        let base = base.min(self.policy.max_delay.as_secs_f32());
+
+        let jitter = self.policy.jitter.clamp(0.0, 1.0);
+        let factor = 1.0 + jitter * (self.next_random() * 2.0 - 1.0);
+
+        Duration::from_secs_f32((base * factor).max(0.0))
+    }
+
+    fn attempt(&self) -> u32 {
+        match self.state {
+            ReconnectState::Waiting { attempt, .. }
+            | ReconnectState::Connecting { attempt }
+            | ReconnectState::WaitingForSlot { attempt, .. } => attempt,
+            _ => 0,
+        }
+    }
+
+    fn schedule(&mut self, attempt: u32) -> ReconnectState {
+        if let Some(max_attempts) = self.policy.max_attempts {
+            if attempt > max_attempts {
+                return ReconnectState::Stopped(StopReason::MaxAttempts);
+            }
+        }
+
+        ReconnectState::Waiting {
+            attempt,
+            at: self.clock.now() + self.delay(attempt),
+        }
+    }
+
+    /// xorshift64, in [0, 1).
+    fn next_random(&mut self) -> f32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+
+        (self.rng >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+
+static mut MANAGER: Option<ReconnectManager<SystemClock>> = None;
+static mut QUERY_RESULT: Option<Receiver<Option<query::QueryInfo>>> = None;
+
+fn handle_event(event: ConnectionEvent) {
+    unsafe {
+        if let Some(manager) = MANAGER.as_mut() {
+            manager.handle_event(event);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectError {
+    /// Only 0.3.7 R3 is supported. R1 and DL R1 have no known addresses for the
+    /// server full, banned and rejected handlers, so the manager would miss those events.
+    UnsupportedVersion,
+}
+
+impl std::fmt::Display for ReconnectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ReconnectError::UnsupportedVersion => "auto-reconnect supports only samp 0.3.7 R3",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ReconnectError {}
+
+/// Starts reconnecting by `policy`. It takes over the `NetGame` connection callbacks
+/// (`on_connected`, `on_closed_connection`, `server_is_full`, `wrong_password`,
+/// `on_banned`, `connection_rejected`) and needs `process` to be called every frame.
+///
+/// Supported on 0.3.7 R3 only, see `ReconnectError::UnsupportedVersion`. Nothing is
+/// hooked on other versions.
+pub fn enable(policy: ReconnectPolicy) -> Result<(), ReconnectError> {
+    if version() != Version::V037R3 {
+        return Err(ReconnectError::UnsupportedVersion);
+    }
+
+    unsafe {
+        MANAGER = Some(ReconnectManager::new(policy, SystemClock::default()));
+        QUERY_RESULT = None;
+    }
+
+    NetGame::on_connected(|| handle_event(ConnectionEvent::Connected));
+    NetGame::on_closed_connection(|| handle_event(ConnectionEvent::ClosedConnection));
+    NetGame::server_is_full(|| handle_event(ConnectionEvent::ServerFull));
+    NetGame::wrong_password(|| handle_event(ConnectionEvent::WrongPassword));
+    NetGame::on_banned(|| handle_event(ConnectionEvent::Banned));
+    NetGame::connection_rejected(|| handle_event(ConnectionEvent::Rejected));
+
+    Ok(())
+}
+
+/// Stops reconnecting, the connection callbacks stay installed but do nothing.
+pub fn disable() {
+    unsafe {
+        MANAGER = None;
+        QUERY_RESULT = None;
+    }
+}
+
+pub fn state() -> Option<ReconnectState> {
+    unsafe { MANAGER.as_ref().map(|manager| manager.state()) }
+}
+
+/// Clears a stop or failed attempts, see `ReconnectManager::reset`.
+pub fn reset() {
+    unsafe {
+        if let Some(manager) = MANAGER.as_mut() {
+            manager.reset();
+        }
+    }
+}
+
+/// Runs due attempts and server queries, call it from the game thread. Until the manager
+/// makes an attempt samp is kept from reconnecting by itself, also after a stop.
+pub fn process() {
+    unsafe {
+        let manager = match MANAGER.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+
+        let holding = !matches!(
+            manager.state(),
+            ReconnectState::Connected | ReconnectState::Connecting { .. }
+        );
+
+        if holding && super::gamestate() == Gamestate::WaitConnect {
+            NetGame::get().postpone_connect();
+        }
+
+        if let Some(receiver) = QUERY_RESULT.as_ref() {
+            match receiver.try_recv() {
+                Ok(info) => {
+                    manager.handle_query_result(info.as_ref());
+                    QUERY_RESULT = None;
+                }
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    manager.handle_query_result(None);
+                    QUERY_RESULT = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => (),
+            }
+        }
+
+        match manager.update() {
+            Some(Action::Reconnect) => {
+                NetGame::get().reconnect();
+            }
+            Some(Action::QueryServer) => match NetGame::get().addr() {
+                Some(addr) => {
+                    let (sender, receiver) = mpsc::channel();
+
+                    std::thread::spawn(move || {
+                        let _ = sender.send(query::query_info(addr, QUERY_TIMEOUT).ok());
+                    });
+
+                    QUERY_RESULT = Some(receiver);
+                }
+                None => manager.handle_query_result(None),
+            },
+            None => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct FakeClock {
+        now: Cell<Duration>,
+    }
+
+    impl FakeClock {
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+    }
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    fn no_jitter() -> ReconnectPolicy {
+        ReconnectPolicy {
+            jitter: 0.0,
+            ..ReconnectPolicy::default()
+        }
+    }
+
+    fn manager(policy: ReconnectPolicy) -> ReconnectManager<FakeClock> {
+        ReconnectManager::with_seed(policy, FakeClock::default(), 42)
+    }
+
+    fn server(players: u16, max_players: u16) -> query::QueryInfo {
+        query::QueryInfo {
+            password: false,
+            players,
+            max_players,
+            hostname: String::new(),
+            gamemode: String::new(),
+            language: String::new(),
+        }
+    }
+
+    /// Fails `attempts` times in a row, making each attempt as soon as it's due.
+    fn fail(manager: &mut ReconnectManager<FakeClock>, attempts: u32) {
+        for _ in 0..attempts {
+            manager.handle_event(ConnectionEvent::ClosedConnection);
+
+            if let ReconnectState::Waiting { at, .. } = manager.state() {
+                manager.clock().now.set(at);
+                assert_eq!(manager.update(), Some(Action::Reconnect));
+            }
+        }
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = ReconnectPolicy {
+            max_attempts: None,
+            ..no_jitter()
+        };
+
+        let mut manager = manager(policy);
+        let delays = [(1, 2), (2, 4), (3, 8), (4, 16), (5, 32), (6, 60), (7, 60), (40, 60)];
+
+        for (attempt, delay) in delays {
+            assert_eq!(manager.delay(attempt), secs(delay), "attempt {}", attempt);
+        }
+
+        manager.clock().advance(secs(10));
+        manager.handle_event(ConnectionEvent::ClosedConnection);
+        let first = ReconnectState::Waiting {
+            attempt: 1,
+            at: secs(12),
+        };
+
+        assert_eq!(manager.state(), first);
+        assert_eq!(manager.update(), None);
+
+        manager.clock().advance(secs(2));
+        assert_eq!(manager.update(), Some(Action::Reconnect));
+        assert_eq!(manager.state(), ReconnectState::Connecting { attempt: 1 });
+
+        manager.handle_event(ConnectionEvent::Rejected);
+        let second = ReconnectState::Waiting {
+            attempt: 2,
+            at: secs(16),
+        };
+
+        assert_eq!(manager.state(), second);
+    }
+
+    #[test]
+    fn jitter() {
+        let policy = ReconnectPolicy {
+            max_attempts: None,
+            ..ReconnectPolicy::default()
+        };
+
+        let cases = [(1, 1.5, 2.5), (2, 3.0, 5.0), (4, 12.0, 20.0), (10, 45.0, 75.0)];
+
+        for seed in [0, 1, 42, u64::MAX] {
+            let mut first = ReconnectManager::with_seed(policy, FakeClock::default(), seed);
+            let mut second = ReconnectManager::with_seed(policy, FakeClock::default(), seed);
+
+            for (attempt, min, max) in cases {
+                let delay = first.delay(attempt);
+                let secs = delay.as_secs_f32();
+
+                assert!(secs >= min && secs <= max, "seed {} attempt {}", seed, attempt);
+                assert_eq!(second.delay(attempt), delay);
+            }
+        }
+    }
+
+    #[test]
+    fn max_attempts() {
+        for max_attempts in [0, 1, 3] {
+            let policy = ReconnectPolicy {
+                max_attempts: Some(max_attempts),
+                ..no_jitter()
+            };
+
+            let mut manager = manager(policy);
+            fail(&mut manager, max_attempts);
+
+            if max_attempts > 0 {
+                let last = ReconnectState::Connecting {
+                    attempt: max_attempts,
+                };
+
+                assert_eq!(manager.state(), last);
+            }
+
+            manager.handle_event(ConnectionEvent::ClosedConnection);
+            let stopped = ReconnectState::Stopped(StopReason::MaxAttempts);
+
+            assert_eq!(manager.state(), stopped);
+
+            manager.clock().advance(secs(3600));
+            assert_eq!(manager.update(), None);
+            manager.handle_event(ConnectionEvent::ClosedConnection);
+            assert_eq!(manager.state(), stopped);
+        }
+    }
+
+    #[test]
+    fn stop_events() {
+        let cases = [
+            (ConnectionEvent::Banned, StopReason::Banned),
+            (ConnectionEvent::WrongPassword, StopReason::WrongPassword),
+        ];
+
+        for (event, reason) in cases {
+            for failures in [0, 2] {
+                let mut manager = manager(no_jitter());
+                fail(&mut manager, failures);
+
+                manager.handle_event(event);
+                assert_eq!(manager.state(), ReconnectState::Stopped(reason));
+
+                manager.handle_event(ConnectionEvent::ServerFull);
+                manager.clock().advance(secs(3600));
+                assert_eq!(manager.update(), None);
+                assert_eq!(manager.state(), ReconnectState::Stopped(reason));
+            }
+        }
+    }
+
+    #[test]
+    fn server_full() {
+        let mut manager = manager(no_jitter());
+
+        manager.handle_event(ConnectionEvent::ServerFull);
+        let waiting = ReconnectState::WaitingForSlot {
+            attempt: 0,
+            next_query: secs(5),
+            query_pending: false,
+        };
+
+        assert_eq!(manager.state(), waiting);
+        assert_eq!(manager.update(), None);
+
+        manager.clock().advance(secs(5));
+        assert_eq!(manager.update(), Some(Action::QueryServer));
+        assert_eq!(manager.update(), None);
+
+        // samp's own failed attempts don't count while waiting
+        manager.handle_event(ConnectionEvent::ServerFull);
+        manager.handle_event(ConnectionEvent::ClosedConnection);
+
+        manager.handle_query_result(Some(&server(50, 50)));
+        manager.clock().advance(secs(5));
+        assert_eq!(manager.update(), Some(Action::QueryServer));
+
+        manager.handle_query_result(None);
+        manager.clock().advance(secs(5));
+        assert_eq!(manager.update(), Some(Action::QueryServer));
+
+        manager.handle_query_result(Some(&server(49, 50)));
+        let slot = ReconnectState::Waiting {
+            attempt: 1,
+            at: secs(15),
+        };
+
+        assert_eq!(manager.state(), slot);
+        assert_eq!(manager.update(), Some(Action::Reconnect));
+
+        manager.handle_event(ConnectionEvent::Connected);
+        assert_eq!(manager.state(), ReconnectState::Connected);
+    }
+
+    #[test]
+    fn server_full_as_failure() {
+        let policy = ReconnectPolicy {
+            wait_while_full: false,
+            ..no_jitter()
+        };
+
+        let mut manager = manager(policy);
+        manager.handle_event(ConnectionEvent::ServerFull);
+        let waiting = ReconnectState::Waiting {
+            attempt: 1,
+            at: secs(2),
+        };
+
+        assert_eq!(manager.state(), waiting);
+    }
+
+    #[test]
+    fn reset() {
+        let events = [
+            ConnectionEvent::Banned,
+            ConnectionEvent::ServerFull,
+            ConnectionEvent::ClosedConnection,
+        ];
+
+        for event in events {
+            let mut manager = manager(no_jitter());
+            fail(&mut manager, 3);
+            manager.handle_event(event);
+
+            manager.reset();
+            assert_eq!(manager.state(), ReconnectState::Connected);
+            assert_eq!(manager.update(), None);
+
+            manager.handle_event(ConnectionEvent::ClosedConnection);
+            let first = ReconnectState::Waiting {
+                attempt: 1,
+                at: manager.clock().now() + secs(2),
+            };
+
+            assert_eq!(manager.state(), first);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+// Sending RPCs to the server through samp's own RakClient, for the few client
+// notifications samp only sends from its input handling (menus, textdraws etc),
+// and reading the payload of incoming ones.
+
+use super::netgame::NetGame;
+
+/// Incoming, the server settings sent once the connection is accepted.
+pub const RPC_INIT_GAME: u8 = 139;
+
+const HIGH_PRIORITY: i32 = 1;
+const RELIABLE_ORDERED: i32 = 9;
+
+// RakClientInterface declares RPC(int*, const char*, unsigned int, ...) right before
+// RPC(int*, RakNet::BitStream*, ...). MSVC puts overloaded virtuals into the vtable in reverse
+// order, so slot 25 is the BitStream overload and 26 the const char* one. This comes from the
+// RakNet 2.x headers samp is built with; the slot hasn't been checked against a disassembly
+// of the R1, R3 or DL builds.
+const RPC_VTABLE_INDEX: usize = 25;
+
+/// `RakNet::BitStream` of RakNet 2.x.
+#[repr(C)]
+struct BitStream {
+    number_of_bits_used: std::os::raw::c_int,
+    number_of_bits_allocated: std::os::raw::c_int,
+    read_offset: std::os::raw::c_int,
+    data: *mut u8,
+    copy_data: bool,
+    stack_data: [u8; 256],
+}
+
+impl BitStream {
+    /// Same as `BitStream(data, length, false)`: reads straight from `data` without copying.
+    fn borrowed(data: &[u8]) -> BitStream {
+        let bits = (data.len() * 8) as std::os::raw::c_int;
+
+        BitStream {
+            number_of_bits_used: bits,
+            number_of_bits_allocated: bits,
+            read_offset: 0,
+            data: data.as_ptr() as *mut u8,
+            copy_data: false,
+            stack_data: [0; 256],
+        }
+    }
+}
+
+type RpcFn = extern "thiscall" fn(
+    *mut (),
+    *mut std::os::raw::c_int,
+    *mut BitStream,
+    std::os::raw::c_int,
+    std::os::raw::c_int,
+    std::os::raw::c_char,
+    bool,
+) -> bool;
+
+/// Sends `data` as the RPC payload. Returns `false` when there is no connection
+/// or RakNet refused to queue it.
+pub fn send_rpc(rpc_id: i32, data: &[u8]) -> bool {
+    let rakclient = NetGame::get().rakclient();
+
+    if rakclient.is_null() {
+        return false;
+    }
+
+    let mut rpc_id = rpc_id;
+    let mut bit_stream = BitStream::borrowed(data);
+
+    unsafe {
+        let vtable = *(rakclient as *const *const usize);
+        let rpc: RpcFn = std::mem::transmute(*vtable.add(RPC_VTABLE_INDEX));
+
+        rpc(
+            rakclient,
+            &mut rpc_id,
+            &mut bit_stream,
+            HIGH_PRIORITY,
+            RELIABLE_ORDERED,
+            0,
+            false,
+        )
+    }
+}
+
+/// `RPCParameters` samp's handlers of incoming RPCs get, only the payload is of interest here.
+#[repr(C)]
+pub struct RpcParameters {
+    pub input: *mut u8,
+    pub bit_length: std::os::raw::c_uint,
+    pub sender: [u8; 6], // PlayerID
+    pub recipient: *mut (),
+    pub reply_to_sender: *mut (),
+}
+
+impl RpcParameters {
+    pub fn data(&self) -> &[u8] {
+        if self.input.is_null() {
+            return &[];
+        }
+
+        let len = (self.bit_length as usize).div_ceil(8);
+        unsafe { std::slice::from_raw_parts(self.input, len) }
+    }
+
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader::new(self.data(), self.bit_length as usize)
+    }
+}
+
+/// Reads a RakNet `BitStream`: values aren't byte aligned and bits go from the highest one.
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    bit_length: usize,
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8], bit_length: usize) -> BitReader<'a> {
+        BitReader {
+            data,
+            bit_length: bit_length.min(data.len() * 8),
+            position: 0,
+        }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bit_length - self.position
+    }
+
+    pub fn skip(&mut self, bits: usize) -> Option<()> {
+        if bits > self.remaining() {
+            return None;
+        }
+
+        self.position += bits;
+        Some(())
+    }
+
+    pub fn read_bit(&mut self) -> Option<bool> {
+        if self.remaining() == 0 {
+            return None;
+        }
+
+        let bit = self.data[self.position / 8] & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        Some(bit)
+    }
+
+    pub fn read_u8(&mut self) -> Option<u8> {
+        if self.remaining() < 8 {
+            return None;
+        }
+
+        (0..8).try_fold(0u8, |byte, _| self.read_bit().map(|bit| byte << 1 | bit as u8))
+    }
+
+    pub fn read_bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.remaining() < N * 8 {
+            return None;
+        }
+
+        let mut bytes = [0; N];
+
+        for byte in bytes.iter_mut() {
+            *byte = self.read_u8()?;
+        }
+
+        Some(bytes)
+    }
+
+    pub fn read_u16(&mut self) -> Option<u16> {
+        self.read_bytes().map(u16::from_le_bytes)
+    }
+
+    pub fn read_u32(&mut self) -> Option<u32> {
+        self.read_bytes().map(u32::from_le_bytes)
+    }
+
+    pub fn read_f32(&mut self) -> Option<f32> {
+        self.read_bytes().map(f32::from_le_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_reader() {
+        // 1, then 0x1234 shifted by a bit, then 0x7F
+        let data = [0x89, 0x1A, 0x3F, 0x80];
+        let mut reader = BitReader::new(&data, 25);
+
+        assert_eq!(reader.read_bit(), Some(true));
+        assert_eq!(reader.read_u16(), Some(0x3412));
+        assert_eq!(reader.remaining(), 8);
+        assert_eq!(reader.read_u8(), Some(0x7F));
+        assert_eq!(reader.read_bit(), None);
+        assert_eq!(reader.read_u32(), None);
+    }
+
+    #[test]
+    fn bit_reader_bounds() {
+        let data = [0xFF; 2];
+
+        assert_eq!(BitReader::new(&data, 64).remaining(), 16);
+        assert_eq!(BitReader::new(&data, 12).read_u16(), None);
+        assert_eq!(BitReader::new(&data, 12).skip(13), None);
+    }
+}
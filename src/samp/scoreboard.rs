@@ -0,0 +1,152 @@
+use retour::GenericDetour;
+
+use super::inputs::DXUTListBox;
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+struct DrawHook {
+    hook: GenericDetour<extern "thiscall" fn(*mut ())>,
+    callback: Box<dyn FnMut() -> bool>,
+}
+
+static mut DRAW_HOOK: Option<DrawHook> = None;
+
+extern "thiscall" fn scoreboard_draw(this: *mut ()) {
+    unsafe {
+        if let Some(hook) = DRAW_HOOK.as_mut() {
+            if !(hook.callback)() {
+                hook.hook.call(this);
+            }
+        }
+    }
+}
+
+fn scoreboard_ptr() -> Option<*mut ()> {
+    match version() {
+        Version::V037 => r1::scoreboard().map(|board| board as *mut _ as *mut ()),
+        Version::V037R3 => r3::scoreboard().map(|board| board as *mut _ as *mut ()),
+        Version::V03DLR1 => dl::scoreboard().map(|board| board as *mut _ as *mut ()),
+        _ => None,
+    }
+}
+
+fn list_box<'a>() -> Option<&'a mut DXUTListBox> {
+    let ptr = match version() {
+        Version::V037 => r1::scoreboard().map(|board| board.m_pListBox),
+        Version::V037R3 => r3::scoreboard().map(|board| board.m_pListBox),
+        Version::V03DLR1 => dl::scoreboard().map(|board| board.m_pListBox),
+        _ => None,
+    }?;
+
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { Some(&mut *ptr) }
+    }
+}
+
+pub fn is_open() -> bool {
+    let enabled = match version() {
+        Version::V037 => r1::scoreboard().map(|board| board.m_bIsEnabled),
+        Version::V037R3 => r3::scoreboard().map(|board| board.m_bIsEnabled),
+        Version::V03DLR1 => dl::scoreboard().map(|board| board.m_bIsEnabled),
+        _ => None,
+    };
+
+    enabled.map(|enabled| enabled != 0).unwrap_or(false)
+}
+
+/// Number of players the scoreboard listed when it was last refreshed.
+pub fn player_count() -> usize {
+    let count = match version() {
+        Version::V037 => r1::scoreboard().map(|board| board.m_nPlayerCount),
+        Version::V037R3 => r3::scoreboard().map(|board| board.m_nPlayerCount),
+        Version::V03DLR1 => dl::scoreboard().map(|board| board.m_nPlayerCount),
+        _ => None,
+    };
+
+    count.unwrap_or(0).max(0) as usize
+}
+
+/// Opens the scoreboard like pressing Tab, samp asks the server for fresh scores and pings.
+pub fn open() {
+    let address = match version() {
+        Version::V037 => r1::CSCOREBOARD_ENABLE,
+        Version::V037R3 => r3::CSCOREBOARD_ENABLE,
+        Version::V03DLR1 => dl::CSCOREBOARD_ENABLE,
+        _ => return,
+    };
+
+    if let Some(scoreboard) = scoreboard_ptr() {
+        unsafe {
+            let enable: extern "thiscall" fn(*mut ()) =
+                std::mem::transmute(super::handle().add(address));
+
+            enable(scoreboard);
+        }
+    }
+}
+
+/// Closes the scoreboard, `hide_cursor` also gives the mouse back to the game.
+pub fn close(hide_cursor: bool) {
+    let address = match version() {
+        Version::V037 => r1::CSCOREBOARD_CLOSE,
+        Version::V037R3 => r3::CSCOREBOARD_CLOSE,
+        Version::V03DLR1 => dl::CSCOREBOARD_CLOSE,
+        _ => return,
+    };
+
+    if let Some(scoreboard) = scoreboard_ptr() {
+        unsafe {
+            let close: extern "thiscall" fn(*mut (), bool) =
+                std::mem::transmute(super::handle().add(address));
+
+            close(scoreboard, hide_cursor);
+        }
+    }
+}
+
+/// Row highlighted in the player list, rows are sorted by player id.
+pub fn selected_row() -> Option<usize> {
+    if !is_open() {
+        return None;
+    }
+
+    list_box().and_then(|list| list.selected())
+}
+
+pub fn set_selected_row(row: usize) -> bool {
+    is_open() && list_box().map(|list| list.select(row)).unwrap_or(false)
+}
+
+/// First visible row of the player list.
+pub fn scroll_position() -> usize {
+    list_box()
+        .map(|list| list.scroll_position.max(0) as usize)
+        .unwrap_or(0)
+}
+
+/// Called every frame samp draws the scoreboard. Returning `true` skips samp's own
+/// drawing so an overlay can replace it, input is still handled by samp.
+pub fn on_draw<F: FnMut() -> bool + 'static>(callback: F) {
+    let address = match version() {
+        Version::V037 => r1::CSCOREBOARD_DRAW,
+        Version::V037R3 => r3::CSCOREBOARD_DRAW,
+        Version::V03DLR1 => dl::CSCOREBOARD_DRAW,
+        _ => return,
+    };
+
+    unsafe {
+        let ptr = super::handle().add(address);
+        let func: extern "thiscall" fn(*mut ()) = std::mem::transmute(ptr);
+
+        if let Ok(hook) = GenericDetour::new(func, scoreboard_draw) {
+            let _ = hook.enable();
+
+            DRAW_HOOK = Some(DrawHook {
+                hook,
+                callback: Box::new(callback),
+            });
+        }
+    }
+}
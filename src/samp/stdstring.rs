@@ -0,0 +1,362 @@
+// MSVC `std::string` as samp keeps it. Plain layout code without any winapi or hooking
+// dependency, so the layout tests run on any host.
+
+/// Where the heap buffers of a `CStdString` come from, it has to be the allocator of
+/// whoever owns the string.
+pub trait StringAllocator {
+    fn alloc(&self, size: usize) -> *mut u8;
+    fn free(&self, ptr: *mut u8);
+}
+
+/// MSVC `std::string`: the text is inline while it fits 15 bytes, otherwise the first
+/// bytes hold a pointer to a heap buffer of `capacity + 1` bytes.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct CStdString {
+    bytes: [u8; 16], // it's like union
+    len: u32,
+    capacity: u32,
+}
+
+// Same on x86 and x86_64 hosts, the pointer always fits the union.
+const _: () = assert!(std::mem::size_of::<CStdString>() == 24);
+const _: () = assert!(std::mem::align_of::<CStdString>() == 4);
+
+impl CStdString {
+    pub const SSO_CAPACITY: u32 = 15;
+    /// Anything longer is treated as garbage, samp never keeps strings this long.
+    pub const MAX_LENGTH: u32 = 0x10_0000;
+
+    /// An empty string as a default constructed one.
+    pub fn new() -> CStdString {
+        CStdString {
+            bytes: [0; 16],
+            len: 0,
+            capacity: Self::SSO_CAPACITY,
+        }
+    }
+
+    fn heap_ptr(&self) -> *mut u8 {
+        unsafe { (&self.bytes as *const _ as *const usize).read_unaligned() as *mut u8 }
+    }
+
+    fn set_heap_ptr(&mut self, ptr: *mut u8) {
+        self.bytes = [0; 16];
+        unsafe { (&mut self.bytes as *mut _ as *mut usize).write_unaligned(ptr as usize) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    pub fn is_heap(&self) -> bool {
+        self.capacity > Self::SSO_CAPACITY
+    }
+
+    /// Checks the header makes sense before anything is read through it.
+    pub fn is_valid(&self) -> bool {
+        self.capacity >= Self::SSO_CAPACITY
+            && self.capacity <= Self::MAX_LENGTH
+            && self.len <= self.capacity
+            && (!self.is_heap() || !self.heap_ptr().is_null())
+    }
+
+    /// `None` when the header is broken.
+    pub fn try_bytes(&self) -> Option<&[u8]> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let len = self.len as usize;
+
+        if self.is_heap() {
+            unsafe { Some(std::slice::from_raw_parts(self.heap_ptr(), len)) }
+        } else {
+            Some(&self.bytes[0..len])
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        self.try_bytes().unwrap_or(&[])
+    }
+
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(self.bytes())
+    }
+
+    pub fn as_str_unchecked(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(self.bytes()) }
+    }
+
+    pub fn to_string(&self) -> String {
+        self.as_str_unchecked().to_owned()
+    }
+
+    /// Replaces the text without reallocating, fails when it doesn't fit the current capacity.
+    pub fn assign_in_place(&mut self, text: &[u8]) -> bool {
+        if !self.is_valid() || text.len() > self.capacity as usize {
+            return false;
+        }
+
+        let ptr = if self.is_heap() {
+            self.heap_ptr()
+        } else {
+            self.bytes.as_mut_ptr()
+        };
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(text.as_ptr(), ptr, text.len());
+            ptr.add(text.len()).write(0);
+        }
+
+        self.len = text.len() as u32;
+        true
+    }
+
+    /// Replaces the text of an inline string, fails for heap strings and text over 15 bytes.
+    /// Without the allocator of the string's owner this is the only safe write.
+    pub fn assign_inline(&mut self, text: &[u8]) -> bool {
+        if self.is_heap() || text.len() > Self::SSO_CAPACITY as usize {
+            return false;
+        }
+
+        self.assign_in_place(text)
+    }
+
+    /// Replaces the text of a string living in game memory, growing it with `allocator`
+    /// like `std::string::assign` would. The old buffer is freed with `allocator` too, so it
+    /// has to be the one that allocated it.
+    pub fn assign<A: StringAllocator>(&mut self, text: &[u8], allocator: &A) -> bool {
+        if text.len() as u64 > Self::MAX_LENGTH as u64 || !self.is_valid() {
+            return false;
+        }
+
+        if self.assign_in_place(text) {
+            return true;
+        }
+
+        let capacity = (text.len() as u32 | Self::SSO_CAPACITY)
+            .max(self.capacity + self.capacity / 2)
+            .min(Self::MAX_LENGTH);
+
+        let ptr = allocator.alloc(capacity as usize + 1);
+
+        if ptr.is_null() {
+            return false;
+        }
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(text.as_ptr(), ptr, text.len());
+            ptr.add(text.len()).write(0);
+        }
+
+        if self.is_heap() {
+            allocator.free(self.heap_ptr());
+        }
+
+        self.set_heap_ptr(ptr);
+        self.len = text.len() as u32;
+        self.capacity = capacity;
+        true
+    }
+}
+
+impl Default for CStdString {
+    fn default() -> CStdString {
+        CStdString::new()
+    }
+}
+
+/// A `std::string` owned by Rust, for samp functions taking `const std::string&`.
+/// samp must not keep a pointer to it nor free it.
+pub struct OwnedStdString {
+    raw: CStdString,
+    _heap: Option<Box<[u8]>>,
+}
+
+impl OwnedStdString {
+    pub fn new(text: &[u8]) -> OwnedStdString {
+        let mut raw = CStdString::new();
+
+        if text.len() <= CStdString::SSO_CAPACITY as usize {
+            raw.bytes[..text.len()].copy_from_slice(text);
+            raw.len = text.len() as u32;
+
+            return OwnedStdString { raw, _heap: None };
+        }
+
+        let mut heap = Vec::with_capacity(text.len() + 1);
+        heap.extend_from_slice(text);
+        heap.push(0);
+
+        let mut heap = heap.into_boxed_slice();
+
+        raw.set_heap_ptr(heap.as_mut_ptr());
+        raw.len = text.len() as u32;
+        raw.capacity = text.len() as u32;
+
+        OwnedStdString {
+            raw,
+            _heap: Some(heap),
+        }
+    }
+
+    pub fn as_raw(&self) -> &CStdString {
+        &self.raw
+    }
+
+    pub fn as_ptr(&self) -> *const CStdString {
+        &self.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(bytes: [u8; 16], len: u32, capacity: u32) -> CStdString {
+        CStdString {
+            bytes,
+            len,
+            capacity,
+        }
+    }
+
+    #[test]
+    fn owned_strings() {
+        let texts: [&[u8]; 5] = [b"", b"Nick_Name", &[b'a'; 15], &[b'b'; 16], &[b'c'; 300]];
+
+        for text in texts {
+            let owned = OwnedStdString::new(text);
+            let raw = owned.as_raw();
+            let heap = text.len() > 15;
+
+            assert!(raw.is_valid());
+            assert_eq!(raw.is_heap(), heap);
+            assert_eq!(raw.len(), text.len());
+            assert_eq!(raw.capacity(), text.len().max(15));
+            assert_eq!(raw.try_bytes(), Some(text));
+
+            let terminator = if heap {
+                unsafe { *raw.heap_ptr().add(text.len()) }
+            } else {
+                raw.bytes[text.len()]
+            };
+
+            assert_eq!(terminator, 0);
+        }
+    }
+
+    #[test]
+    fn sso_layout() {
+        let owned = OwnedStdString::new(b"abc");
+        let raw = owned.as_raw();
+
+        assert_eq!(&raw.bytes[..4], b"abc\0");
+        assert_eq!(raw.as_str(), Ok("abc"));
+
+        let empty = CStdString::default();
+
+        assert!(empty.is_valid() && empty.is_empty());
+        assert_eq!(empty.try_bytes(), Some(&b""[..]));
+    }
+
+    #[test]
+    fn broken_headers() {
+        let mut heap = [0u8; 16];
+        let text = [b'x'; 32];
+        let pointer = (text.as_ptr() as usize).to_le_bytes();
+        heap[..pointer.len()].copy_from_slice(&pointer);
+
+        let cases = [
+            ("sso len over capacity", header([0; 16], 16, 15), false),
+            ("heap len over capacity", header(heap, 33, 32), false),
+            ("capacity under sso", header([0; 16], 3, 14), false),
+            ("null heap pointer", header([0; 16], 20, 31), false),
+            ("capacity over max", header(heap, 3, CStdString::MAX_LENGTH + 1), false),
+            ("sso", header(*b"abc\0\0\0\0\0\0\0\0\0\0\0\0\0", 3, 15), true),
+            ("heap", header(heap, 32, 32), true),
+        ];
+
+        for (name, string, valid) in cases {
+            assert_eq!(string.is_valid(), valid, "{}", name);
+            assert_eq!(string.try_bytes().is_some(), valid, "{}", name);
+
+            if !valid {
+                assert_eq!(string.bytes(), b"", "{}", name);
+            }
+        }
+    }
+
+    struct TestAllocator;
+
+    impl StringAllocator for TestAllocator {
+        fn alloc(&self, size: usize) -> *mut u8 {
+            Box::into_raw(vec![0xCCu8; size].into_boxed_slice()) as *mut u8
+        }
+
+        fn free(&self, _ptr: *mut u8) {
+            // the size isn't known here, the test buffers are leaked
+        }
+    }
+
+    #[test]
+    fn assign_in_place() {
+        let mut owned = OwnedStdString::new(&[b'a'; 20]);
+        let raw = &mut owned.raw;
+
+        assert!(raw.assign_in_place(b"short"));
+        assert_eq!(raw.try_bytes(), Some(&b"short"[..]));
+        assert_eq!(unsafe { *raw.heap_ptr().add(5) }, 0);
+        assert!(!raw.assign_in_place(&[b'b'; 21]));
+        assert_eq!(raw.len(), 5);
+
+        let mut sso = CStdString::new();
+
+        assert!(sso.assign_in_place(&[b'c'; 15]));
+        assert_eq!(sso.bytes[15], 0);
+        assert!(!sso.assign_in_place(&[b'c'; 16]));
+    }
+
+    #[test]
+    fn assign_inline() {
+        let mut sso = CStdString::new();
+
+        assert!(sso.assign_inline(b"Nick_Name"));
+        assert_eq!(sso.try_bytes(), Some(&b"Nick_Name"[..]));
+        assert!(!sso.assign_inline(&[b'd'; 16]));
+        assert_eq!(sso.len(), 9);
+
+        let mut owned = OwnedStdString::new(&[b'a'; 20]);
+
+        assert!(!owned.raw.assign_inline(b"short"));
+        assert_eq!(owned.raw.len(), 20);
+    }
+
+    #[test]
+    fn assign() {
+        let mut string = CStdString::new();
+
+        assert!(string.assign(b"short", &TestAllocator));
+        assert!(!string.is_heap());
+
+        assert!(string.assign(&[b'e'; 40], &TestAllocator));
+        assert!(string.is_heap() && string.is_valid());
+        assert_eq!(string.try_bytes(), Some(&[b'e'; 40][..]));
+        assert_eq!(unsafe { *string.heap_ptr().add(40) }, 0);
+
+        let capacity = string.capacity();
+
+        assert!(string.assign(b"back", &TestAllocator));
+        assert_eq!((string.len(), string.capacity()), (4, capacity));
+        assert!(!string.assign(&vec![b'f'; CStdString::MAX_LENGTH as usize + 1], &TestAllocator));
+    }
+}
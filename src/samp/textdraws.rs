@@ -0,0 +1,573 @@
+use super::version::{version, Version};
+use super::{handle, BOOL, D3DCOLOR, ID};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::color::Rgba;
+use crate::gta::matrix::CVector;
+
+pub const MAX_TEXTDRAWS: usize = 2048;
+pub const MAX_PLAYER_TEXTDRAWS: usize = 256;
+
+/// `INVALID_TEXT_DRAW`, what samp keeps in `m_nHoveredId` while nothing is hovered.
+const INVALID_TEXTDRAW: ID = 0xFFFF;
+
+/// Global textdraws take the first `MAX_TEXTDRAWS` slots of the pool, player ones go after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextDrawId {
+    Global(u16),
+    Player(u16),
+}
+
+impl TextDrawId {
+    pub fn from_index(index: usize) -> Option<TextDrawId> {
+        if index < MAX_TEXTDRAWS {
+            Some(TextDrawId::Global(index as u16))
+        } else if index < MAX_TEXTDRAWS + MAX_PLAYER_TEXTDRAWS {
+            Some(TextDrawId::Player((index - MAX_TEXTDRAWS) as u16))
+        } else {
+            None
+        }
+    }
+
+    /// Slot in `CTextDrawPool`, which is also the id samp sends in click notifications.
+    pub fn index(&self) -> usize {
+        match *self {
+            TextDrawId::Global(id) => id as usize,
+            TextDrawId::Player(id) => MAX_TEXTDRAWS + id as usize,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDrawFont {
+    Beckett,
+    Aharoni,
+    BankGothic,
+    Pricedown,
+    /// `txd:texture` sprite.
+    Sprite,
+    ModelPreview,
+}
+
+impl TextDrawFont {
+    pub fn from_raw(style: i32) -> Option<TextDrawFont> {
+        match style {
+            0 => Some(TextDrawFont::Beckett),
+            1 => Some(TextDrawFont::Aharoni),
+            2 => Some(TextDrawFont::BankGothic),
+            3 => Some(TextDrawFont::Pricedown),
+            4 => Some(TextDrawFont::Sprite),
+            5 => Some(TextDrawFont::ModelPreview),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDrawAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl TextDrawAlignment {
+    pub fn from_flags(left: bool, center: bool, right: bool) -> TextDrawAlignment {
+        if center {
+            TextDrawAlignment::Center
+        } else if right && !left {
+            TextDrawAlignment::Right
+        } else {
+            TextDrawAlignment::Left
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PreviewModel {
+    pub model: u16,
+    pub rotation: CVector,
+    pub zoom: f32,
+    /// Vehicle colors, `-1` keeps the default ones.
+    pub colors: (i16, i16),
+}
+
+/// Rectangle in screen pixels, `right` and `bottom` are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenRect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl ScreenRect {
+    /// `None` for an empty rectangle, which is what samp keeps until a textdraw is drawn.
+    pub fn from_rect(rect: [std::os::raw::c_long; 4]) -> Option<ScreenRect> {
+        let rect = ScreenRect {
+            left: rect[0] as i32,
+            top: rect[1] as i32,
+            right: rect[2] as i32,
+            bottom: rect[3] as i32,
+        };
+
+        if rect.right > rect.left && rect.bottom > rect.top {
+            Some(rect)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.left && x < self.right && y >= self.top && y < self.bottom
+    }
+
+    pub fn center(&self) -> (i32, i32) {
+        ((self.left + self.right) / 2, (self.top + self.bottom) / 2)
+    }
+}
+
+/// Copy of a textdraw. Positions and sizes are in samp's 640x448 canvas units.
+#[derive(Debug, Clone)]
+pub struct TextDraw {
+    pub id: TextDrawId,
+    /// Text as the server sent it, with `~x~` tokens.
+    pub text: String,
+    pub position: (f32, f32),
+    pub letter_size: (f32, f32),
+    pub letter_color: Rgba,
+    pub font: Option<TextDrawFont>,
+    pub alignment: TextDrawAlignment,
+    pub proportional: bool,
+    pub shadow: u8,
+    pub outline: u8,
+    pub background_color: Rgba,
+    pub use_box: bool,
+    /// `TextDrawTextSize`: the right edge (or width when centered) and the height.
+    pub box_size: (f32, f32),
+    pub box_color: Rgba,
+    pub preview: Option<PreviewModel>,
+    pub selectable: bool,
+    /// Area samp checks the cursor against, known once the textdraw has been drawn.
+    pub rect: Option<ScreenRect>,
+}
+
+impl TextDraw {
+    pub fn is_global(&self) -> bool {
+        matches!(self.id, TextDrawId::Global(_))
+    }
+
+    /// Text split into colored spans using `letter_color` as the default color.
+    pub fn spans(&self) -> Vec<GameTextSpan> {
+        parse_game_text(&self.text, self.letter_color)
+    }
+
+    /// Text without any `~x~` tokens, `~n~` becomes a line break.
+    pub fn plain_text(&self) -> String {
+        self.spans().into_iter().map(|span| span.text).collect()
+    }
+}
+
+/// Every textdraw that exists now, global ones first.
+pub fn textdraws() -> Vec<TextDraw> {
+    let textdraws = match version() {
+        Version::V037 => r1::textdraws().map(|iter| {
+            iter.filter_map(|(index, textdraw)| Some(textdraw.info(TextDrawId::from_index(index)?)))
+                .collect()
+        }),
+        Version::V037R3 => r3::textdraws().map(|iter| {
+            iter.filter_map(|(index, textdraw)| Some(textdraw.info(TextDrawId::from_index(index)?)))
+                .collect()
+        }),
+        Version::V03DLR1 => dl::textdraws().map(|iter| {
+            iter.filter_map(|(index, textdraw)| Some(textdraw.info(TextDrawId::from_index(index)?)))
+                .collect()
+        }),
+        _ => None,
+    };
+
+    textdraws.unwrap_or_else(Vec::new)
+}
+
+pub fn global_textdraws() -> Vec<TextDraw> {
+    textdraws()
+        .into_iter()
+        .filter(|textdraw| textdraw.is_global())
+        .collect()
+}
+
+pub fn player_textdraws() -> Vec<TextDraw> {
+    textdraws()
+        .into_iter()
+        .filter(|textdraw| !textdraw.is_global())
+        .collect()
+}
+
+pub fn textdraw(id: TextDrawId) -> Option<TextDraw> {
+    let index = id.index();
+
+    match version() {
+        Version::V037 => r1::find_textdraw(index).map(|textdraw| textdraw.info(id)),
+        Version::V037R3 => r3::find_textdraw(index).map(|textdraw| textdraw.info(id)),
+        Version::V03DLR1 => dl::find_textdraw(index).map(|textdraw| textdraw.info(id)),
+        _ => None,
+    }
+}
+
+/// samp's `CTextDrawSelection`, active between `SelectTextDraw` and `CancelSelectTextDraw`.
+#[repr(C, packed)]
+pub struct TextDrawSelection {
+    pub m_bIsActive: BOOL,
+    pub m_hoveredColor: D3DCOLOR,
+    pub m_nHoveredId: ID,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickError {
+    /// The server hasn't called `SelectTextDraw`.
+    NotSelecting,
+    /// No such textdraw or it isn't selectable.
+    NotSelectable,
+}
+
+impl std::fmt::Display for ClickError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ClickError::NotSelecting => "textdraw selection isn't active",
+            ClickError::NotSelectable => "textdraw isn't selectable",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ClickError {}
+
+impl TextDrawSelection {
+    pub fn get<'a>() -> Option<&'a mut TextDrawSelection> {
+        let selection_addr = match version() {
+            Version::V037 => r1::CTEXTDRAWSELECTION,
+            Version::V037R3 => r3::CTEXTDRAWSELECTION,
+            Version::V03DLR1 => dl::CTEXTDRAWSELECTION,
+            _ => return None,
+        };
+
+        let ptr = unsafe { (handle().add(selection_addr) as *mut *mut TextDrawSelection).read() };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &mut *ptr })
+        }
+    }
+
+    /// Sends the click notification for `m_nHoveredId`, `INVALID_TEXT_DRAW` means the selection
+    /// was cancelled.
+    fn send_notification(&mut self) {
+        let address = match version() {
+            Version::V037 => r1::CTEXTDRAWSELECTION_SENDNOTIFICATION,
+            Version::V037R3 => r3::CTEXTDRAWSELECTION_SENDNOTIFICATION,
+            Version::V03DLR1 => dl::CTEXTDRAWSELECTION_SENDNOTIFICATION,
+            _ => return,
+        };
+
+        unsafe {
+            let send_notification: extern "thiscall" fn(*mut TextDrawSelection) =
+                std::mem::transmute(handle().add(address));
+
+            send_notification(self);
+        }
+    }
+
+    /// `CTextDrawSelection::Disable`: clears the flag and the hovered id and hides the cursor.
+    fn disable(&mut self) {
+        let address = match version() {
+            Version::V037 => r1::CTEXTDRAWSELECTION_DISABLE,
+            Version::V037R3 => r3::CTEXTDRAWSELECTION_DISABLE,
+            Version::V03DLR1 => dl::CTEXTDRAWSELECTION_DISABLE,
+            _ => return,
+        };
+
+        unsafe {
+            let disable: extern "thiscall" fn(*mut TextDrawSelection) =
+                std::mem::transmute(handle().add(address));
+
+            disable(self);
+        }
+    }
+}
+
+pub fn is_selecting() -> bool {
+    TextDrawSelection::get()
+        .map(|selection| selection.m_bIsActive != 0)
+        .unwrap_or(false)
+}
+
+/// Textdraw under the cursor while selecting.
+pub fn hovered() -> Option<TextDrawId> {
+    TextDrawSelection::get()
+        .filter(|selection| selection.m_bIsActive != 0)
+        .and_then(|selection| TextDrawId::from_index(selection.m_nHoveredId as usize))
+}
+
+/// Textdraws that can be clicked now, empty when selection isn't active.
+pub fn selectable_textdraws() -> Vec<TextDraw> {
+    if !is_selecting() {
+        return Vec::new();
+    }
+
+    textdraws()
+        .into_iter()
+        .filter(|textdraw| textdraw.selectable)
+        .collect()
+}
+
+/// Clicks a textdraw as if the player did, the server gets `OnPlayer(PlayerClick)TextDraw`.
+pub fn click(id: TextDrawId) -> Result<(), ClickError> {
+    let selection = TextDrawSelection::get()
+        .filter(|selection| selection.m_bIsActive != 0)
+        .ok_or(ClickError::NotSelecting)?;
+
+    let is_selectable = textdraw(id)
+        .map(|textdraw| textdraw.selectable)
+        .unwrap_or(false);
+
+    if !is_selectable {
+        return Err(ClickError::NotSelectable);
+    }
+
+    let hovered = selection.m_nHoveredId;
+
+    selection.m_nHoveredId = id.index() as ID;
+    selection.send_notification();
+    selection.m_nHoveredId = hovered;
+
+    Ok(())
+}
+
+/// Leaves selection mode like pressing Esc: the server gets `INVALID_TEXT_DRAW`
+/// and the cursor is hidden. Returns `false` if selection wasn't active.
+pub fn cancel_selection() -> bool {
+    let selection = match TextDrawSelection::get() {
+        Some(selection) if selection.m_bIsActive != 0 => selection,
+        _ => return false,
+    };
+
+    selection.m_nHoveredId = INVALID_TEXTDRAW;
+    selection.send_notification();
+    selection.disable();
+
+    true
+}
+
+/// A run of game text drawn with one color. Line breaks are kept as `\n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameTextSpan {
+    pub color: Rgba,
+    pub text: String,
+}
+
+// CFont token colors
+const RED: Rgba = Rgba::new(180, 25, 29, 255);
+const GREEN: Rgba = Rgba::new(54, 104, 44, 255);
+const BLUE: Rgba = Rgba::new(50, 60, 127, 255);
+const YELLOW: Rgba = Rgba::new(226, 192, 99, 255);
+const PURPLE: Rgba = Rgba::new(168, 110, 252, 255);
+const WHITE: Rgba = Rgba::new(225, 225, 225, 255);
+const BLACK: Rgba = Rgba::new(0, 0, 0, 255);
+
+fn lighten(color: Rgba) -> Rgba {
+    let channel = |value: u8| (value as f32 * 1.5).min(255.0) as u8;
+    Rgba::new(channel(color.r), channel(color.g), channel(color.b), color.a)
+}
+
+/// Splits GTA formatted text into spans: `~r~ ~g~ ~b~ ~y~ ~p~ ~w~ ~l~` set the color
+/// (keeping the alpha of `base`), `~s~` goes back to `base`, every `~h~` lightens
+/// the current color and `~n~` breaks the line. `~k~~ACTION~` gives the action name,
+/// arrows become `↑ ↓ ← →` and other tokens are dropped.
+pub fn parse_game_text(text: &str, base: Rgba) -> Vec<GameTextSpan> {
+    let mut spans: Vec<GameTextSpan> = Vec::new();
+    let mut color = base;
+    let mut current = String::new();
+    let mut rest = text;
+
+    let mut flush = |current: &mut String, color: Rgba| {
+        if current.is_empty() {
+            return;
+        }
+
+        match spans.last_mut() {
+            Some(last) if last.color == color => last.text.push_str(current),
+            _ => spans.push(GameTextSpan {
+                color,
+                text: current.clone(),
+            }),
+        }
+
+        current.clear();
+    };
+
+    while let Some(start) = rest.find('~') {
+        current.push_str(&rest[..start]);
+
+        let after = &rest[start + 1..];
+
+        let end = match after.find('~') {
+            Some(end) => end,
+            None => {
+                // a lone tilde is printed as is
+                current.push_str(&rest[start..]);
+                rest = "";
+                break;
+            }
+        };
+
+        let token = &after[..end];
+        rest = &after[end + 1..];
+
+        let with_alpha = |token_color: Rgba| token_color.with_alpha(base.a);
+
+        let new_color = match token.to_ascii_lowercase().as_str() {
+            "r" => Some(with_alpha(RED)),
+            "g" => Some(with_alpha(GREEN)),
+            "b" => Some(with_alpha(BLUE)),
+            "y" => Some(with_alpha(YELLOW)),
+            "p" => Some(with_alpha(PURPLE)),
+            "w" => Some(with_alpha(WHITE)),
+            "l" => Some(with_alpha(BLACK)),
+            "s" => Some(base),
+            "h" => Some(lighten(color)),
+            "n" => {
+                current.push('\n');
+                None
+            }
+            "u" => {
+                current.push('↑');
+                None
+            }
+            "d" => {
+                current.push('↓');
+                None
+            }
+            "<" => {
+                current.push('←');
+                None
+            }
+            ">" => {
+                current.push('→');
+                None
+            }
+            "k" => {
+                if let Some(action) = rest.strip_prefix('~') {
+                    if let Some(end) = action.find('~') {
+                        current.push_str(&action[..end]);
+                        rest = &action[end + 1..];
+                    }
+                }
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(new_color) = new_color {
+            flush(&mut current, color);
+            color = new_color;
+        }
+    }
+
+    current.push_str(rest);
+    flush(&mut current, color);
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: Rgba = Rgba::new(10, 20, 30, 200);
+
+    fn span(color: Rgba, text: &str) -> GameTextSpan {
+        GameTextSpan {
+            color,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(parse_game_text("", BASE).is_empty());
+        assert!(parse_game_text("~r~~h~~s~", BASE).is_empty());
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            parse_game_text("a~r~b~G~c~s~d", BASE),
+            vec![
+                span(BASE, "a"),
+                span(RED.with_alpha(200), "b"),
+                span(GREEN.with_alpha(200), "c"),
+                span(BASE, "d"),
+            ]
+        );
+
+        // color changes without text in between leave no empty spans and equal colors merge
+        assert_eq!(
+            parse_game_text("~r~~g~a~g~b", BASE),
+            vec![span(GREEN.with_alpha(200), "ab")]
+        );
+    }
+
+    #[test]
+    fn nested_highlight() {
+        let red = RED.with_alpha(200);
+
+        assert_eq!(
+            parse_game_text("~r~a~h~b~h~c~h~~h~d", BASE),
+            vec![
+                span(red, "a"),
+                span(Rgba::new(255, 37, 43, 200), "b"),
+                span(Rgba::new(255, 55, 64, 200), "c"),
+                span(Rgba::new(255, 123, 144, 200), "d"),
+            ]
+        );
+        assert_eq!(
+            parse_game_text("~h~a", BASE),
+            vec![span(Rgba::new(15, 30, 45, 200), "a")]
+        );
+        assert_eq!(
+            parse_game_text("~w~~h~a", BASE),
+            vec![span(Rgba::new(255, 255, 255, 200), "a")]
+        );
+    }
+
+    #[test]
+    fn text_tokens() {
+        assert_eq!(
+            parse_game_text("a~n~b~u~~d~~<~~>~", BASE),
+            vec![span(BASE, "a\nb↑↓←→")]
+        );
+        assert_eq!(
+            parse_game_text("press ~k~~VEHICLE_ENTER_EXIT~ now", BASE),
+            vec![span(BASE, "press VEHICLE_ENTER_EXIT now")]
+        );
+    }
+
+    #[test]
+    fn unknown_tokens() {
+        assert_eq!(parse_game_text("a~x~b~zz~c~~d", BASE), vec![span(BASE, "abcd")]);
+        assert_eq!(parse_game_text("~k~", BASE), Vec::new());
+        assert_eq!(parse_game_text("~k~~UNCLOSED", BASE), vec![span(BASE, "~UNCLOSED")]);
+    }
+
+    #[test]
+    fn stray_tilde() {
+        assert_eq!(parse_game_text("~", BASE), vec![span(BASE, "~")]);
+        assert_eq!(parse_game_text("100~ done", BASE), vec![span(BASE, "100~ done")]);
+        assert_eq!(
+            parse_game_text("a~r~b~c", BASE),
+            vec![span(BASE, "a"), span(RED.with_alpha(200), "b~c")]
+        );
+    }
+}
@@ -1,6 +1,22 @@
+use super::chat::{ChatLine, EntryType};
+use super::damage::VehicleDamage;
+use super::deathwindow::KillEvent;
+use super::encoding;
+use super::gangzones::GangZone;
+use super::inputs::DXUTListBox;
+use super::label_pool::{Label, LABEL_POOL_SIZE};
+use super::menus::{Menu, MenuColumn};
+use super::netgame::{PlayerMarkersMode, ServerSettings};
 use super::packets;
+use super::pickups::Pickup;
+use super::textdraws::{
+    PreviewModel, ScreenRect, TextDraw, TextDrawAlignment, TextDrawFont, TextDrawId,
+};
 use super::players::GamePed;
+use super::vehicles::GameVehicle;
 use super::{handle, CStdString, BOOL, D3DCOLOR, GTAREF, ID, NUMBER, TICK};
+use super::GameAllocator;
+use crate::gta::color::Rgba;
 use crate::gta::matrix::{CVector, RwMatrix};
 
 // use std::ffi::{c_void, CStr};
@@ -13,6 +29,28 @@ pub const CGAME_SETCURSORMODE: usize = 0xA0530;
 pub const CGAME_PROCESSINPUTENABLING: usize = 0xA0410;
 pub const CDIALOG: usize = 0x2AC9E0;
 pub const CDEATHWINDOW_DRAW: usize = 0x69D20;
+pub const CINPUT_ADDCOMMAND: usize = 0x69190;
+pub const CINPUT_SEND: usize = 0x69340;
+pub const CINPUT_OPEN: usize = 0x68EC0;
+pub const CINPUT_CLOSE: usize = 0x68FC0;
+pub const CINPUT_PROCESSINPUT: usize = 0x69410;
+pub const CDXUTEDITBOX_SETTEXT: usize = 0x85020;
+pub const CDIALOG_SHOW: usize = 0x6FA70;
+pub const CDIALOG_CLOSE: usize = 0x700F0;
+pub const CTEXTDRAWSELECTION: usize = 0x2AC9F4;
+pub const CTEXTDRAWSELECTION_SENDNOTIFICATION: usize = 0x70F50;
+pub const CTEXTDRAWSELECTION_DISABLE: usize = 0x71030;
+pub const CLOCALPLAYER_CHAT: usize = 0x5860;
+pub const CPICKUPPOOL_SENDNOTIFICATION: usize = 0x13280;
+pub const CDEATHWINDOW: usize = 0x2ACA18;
+pub const CDEATHWINDOW_ADDENTRY: usize = 0x6A010;
+pub const CSCOREBOARD: usize = 0x2AC9DC;
+pub const CSCOREBOARD_ENABLE: usize = 0x6EDE0;
+pub const CSCOREBOARD_CLOSE: usize = 0x6E3D0;
+pub const CSCOREBOARD_DRAW: usize = 0x6EAC0;
+pub const CNETGAME_SHUTDOWNFORRESTART: usize = 0xA230;
+pub const CCHAT: usize = 0x2ACA10;
+pub const CCHAT_ADDENTRY: usize = 0x67650;
 
 const SPEC_MODE_VEHICLE: i8 = 3;
 const SPEC_MODE_PLAYER: i8 = 4;
@@ -44,6 +82,71 @@ impl From<i32> for Gamestate {
     }
 }
 
+impl From<Gamestate> for i32 {
+    /// `Gamestate::None` gives 0, which isn't a samp state.
+    fn from(state: Gamestate) -> i32 {
+        match state {
+            Gamestate::WaitConnect => 0x1,
+            Gamestate::Connecting => 0x2,
+            Gamestate::AwaitJoin => 0x6,
+            Gamestate::Connected => 0x5,
+            Gamestate::Restarting => 0xB,
+            Gamestate::None => 0,
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CNetGame_Settings {
+    pub m_bUseCJWalk: bool,
+    pub m_nDeadDropsMoney: ::std::os::raw::c_uint,
+    pub m_fWorldBoundaries: [f32; 4usize],
+    pub m_bAllowWeapons: bool,
+    pub m_fGravity: f32,
+    pub m_bEnterExit: bool,
+    pub m_bVehicleFriendlyFire: BOOL,
+    pub m_bHoldTime: bool,
+    pub m_bInstagib: bool,
+    pub m_bZoneNames: bool,
+    pub m_bFriendlyFire: bool,
+    pub m_bClassesAvailable: BOOL,
+    pub m_fNameTagsDrawDist: f32,
+    pub m_bManualVehicleEngineAndLight: bool,
+    pub m_nWorldTimeHour: ::std::os::raw::c_uchar,
+    pub m_nWorldTimeMinute: ::std::os::raw::c_uchar,
+    pub m_nWeather: ::std::os::raw::c_uchar,
+    pub m_bNoNametagsBehindWalls: bool,
+    pub m_nPlayerMarkersMode: ::std::os::raw::c_int,
+    pub m_fChatRadius: f32,
+    pub m_bNameTags: bool,
+    pub m_bLtdChatRadius: bool,
+}
+
+impl CNetGame_Settings {
+    pub fn info(&self) -> ServerSettings {
+        ServerSettings {
+            name_tags: self.m_bNameTags,
+            name_tag_draw_distance: self.m_fNameTagsDrawDist,
+            name_tag_los: self.m_bNoNametagsBehindWalls,
+            player_markers: PlayerMarkersMode::from(self.m_nPlayerMarkersMode),
+            limit_chat_radius: self.m_bLtdChatRadius,
+            chat_radius: self.m_fChatRadius,
+            world_time: (self.m_nWorldTimeHour, self.m_nWorldTimeMinute),
+            weather: self.m_nWeather,
+            gravity: self.m_fGravity,
+            enter_exits: self.m_bEnterExit,
+            zone_names: self.m_bZoneNames,
+            cj_walk: self.m_bUseCJWalk,
+            manual_engine_and_lights: self.m_bManualVehicleEngineAndLight,
+            instagib: self.m_bInstagib,
+            death_drop_money: self.m_nDeadDropsMoney,
+            world_boundaries: {self.m_fWorldBoundaries},
+            stunt_bonus: None,
+            lag_compensation: None,
+        }
+    }
+}
+
 #[repr(C, packed)]
 pub struct CNetGame {
     pub pad_0: [::std::os::raw::c_char; 44usize],
@@ -58,7 +161,7 @@ pub struct CNetGame {
     pub m_aMapIcons: [GTAREF; 100usize],
     pub m_nGameState: ::std::os::raw::c_int,
     pub m_lastConnectAttempt: TICK,
-    pub m_pSettings: *mut (),
+    pub m_pSettings: *mut CNetGame_Settings,
     pub pad_2: [::std::os::raw::c_char; 5usize],
     pub m_pPools: *mut CNetGame_Pools,
 }
@@ -82,6 +185,35 @@ impl CNetGame {
         Some(SocketAddr::from((addr, self.m_nPort as u16)))
     }
 
+    pub fn hostname(&self) -> String {
+        encoding::decode_c_buffer(&{ self.m_szHostname })
+    }
+
+    pub fn settings(&self) -> Option<ServerSettings> {
+        if self.m_pSettings.is_null() {
+            None
+        } else {
+            unsafe { Some((*self.m_pSettings).info()) }
+        }
+    }
+
+    /// Address samp connects to next, `host` must be shorter than the buffer.
+    pub fn set_server(&mut self, host: &[u8], port: u16) {
+        let mut address = [0; 257];
+        let len = host.len().min(address.len() - 1);
+
+        address[..len].copy_from_slice(&host[..len]);
+
+        self.m_szHostAddress = address;
+        self.m_nPort = port as ::std::os::raw::c_int;
+    }
+
+    /// Makes samp connect on its next frame, as it does after losing the connection.
+    pub fn wait_connect(&mut self) {
+        self.m_nGameState = Gamestate::WaitConnect.into();
+        self.m_lastConnectAttempt = 0;
+    }
+
     pub fn gamestate(&self) -> Gamestate {
         Gamestate::from(self.m_nGameState)
     }
@@ -89,15 +221,15 @@ impl CNetGame {
 
 #[allow(non_camel_case_types)]
 pub struct CNetGame_Pools {
-    pub m_pMenu: *mut (),
-    pub m_pActor: *mut (),
+    pub m_pMenu: *mut CMenuPool,
+    pub m_pActor: *mut CActorPool,
     pub m_pPlayer: *mut CPlayerPool,
     pub m_pVehicle: *mut CVehiclePool,
-    pub m_pPickup: *mut (),
+    pub m_pPickup: *mut CPickupPool,
     pub m_pObject: *mut CObjectPool,
-    pub m_pGangZone: *mut (),
-    pub m_pLabel: *mut (),
-    pub m_pTextDraw: *mut (),
+    pub m_pGangZone: *mut CGangZonePool,
+    pub m_pLabel: *mut CLabelPool,
+    pub m_pTextDraw: *mut CTextDrawPool,
 }
 
 #[repr(C, packed)]
@@ -282,6 +414,17 @@ impl CRemotePlayer {
         self.m_head.m_direction.clone()
     }
 
+    pub fn current_weapon(&self) -> u8 {
+        let weapon = match self.m_nState {
+            17 => self.m_onfootData.m_nCurrentWeapon,    // onfoot
+            18 => self.m_passengerData.m_nCurrentWeapon, // passenger
+            19 => self.m_incarData.m_nCurrentWeapon,     // driver
+            _ => 0,
+        };
+
+        weapon & 0x3F // the upper bits carry additional keys
+    }
+
     pub fn id(&self) -> ID {
         self.m_nId
     }
@@ -446,6 +589,10 @@ impl CLocalPlayer {
     pub fn id(&self) -> Option<i32> {
         player_pool().map(|players| players.m_localInfo.m_nId as i32)
     }
+
+    pub fn current_weapon(&self) -> u8 {
+        self.m_weaponsData.m_nCurrentWeapon
+    }
 }
 
 #[repr(C, packed)]
@@ -524,8 +671,19 @@ pub struct CLocalPlayer__bindgen_ty_6 {
     pub m_nVehicleUpdating: ID,
     pub m_nBumper: ::std::os::raw::c_int,
     pub m_nDoor: ::std::os::raw::c_int,
-    pub m_bLight: bool,
-    pub m_bWheel: bool,
+    pub m_bLight: ::std::os::raw::c_char,
+    pub m_bWheel: ::std::os::raw::c_char,
+}
+
+impl CLocalPlayer__bindgen_ty_6 {
+    pub fn damage(&self) -> VehicleDamage {
+        VehicleDamage::new(
+            self.m_nBumper as u32,
+            self.m_nDoor as u32,
+            self.m_bLight as u8,
+            Some(self.m_bWheel as u8),
+        )
+    }
 }
 
 #[repr(C, packed)]
@@ -534,7 +692,7 @@ pub struct CVehiclePool {
     pub m_waitingList: CVehiclePool__bindgen_ty_1,
     pub m_pObject: [*mut CVehicle; 2000usize],
     pub m_bNotEmpty: [BOOL; 2000usize],
-    pub m_pGameObject: [*mut GamePed; 2000usize], // GTA::CVehicle
+    pub m_pGameObject: [*mut GameVehicle; 2000usize], // GTA::CVehicle
     pub pad_6ef4: [::std::os::raw::c_uint; 2000usize],
     pub m_nLastUndrivenId: [ID; 2000usize],
     pub m_lastUndrivenProcessTick: [TICK; 2000usize],
@@ -562,6 +720,17 @@ pub struct CVehiclePool_Info {
     pub m_bHasSiren: bool,
 }
 
+impl CVehiclePool_Info {
+    pub fn damage(&self) -> VehicleDamage {
+        VehicleDamage::new(
+            self.m_nPanelDamageStatus as u32,
+            self.m_nDoorDamageStatus as u32,
+            self.m_nLightDamageStatus as u8,
+            None,
+        )
+    }
+}
+
 #[repr(C, packed)]
 pub struct CVehiclePool__bindgen_ty_1 {
     pub m_entry: [CVehiclePool_Info; 100usize],
@@ -572,7 +741,7 @@ pub struct CVehiclePool__bindgen_ty_1 {
 pub struct CVehicle {
     pub _base: CEntity,
     pub m_pTrailer: *mut CVehicle,
-    pub m_pGameVehicle: *mut GamePed, // GTA::CVehicle
+    pub m_pGameVehicle: *mut GameVehicle, // GTA::CVehicle
     pub pad_5c: [::std::os::raw::c_char; 8usize],
     pub m_bIsInvulnerable: BOOL,
     pub m_bIsLightsOn: BOOL,
@@ -736,6 +905,424 @@ pub struct CObject__bindgen_ty_1__bindgen_ty_2__bindgen_ty_1 {
     pub m_align: ::std::os::raw::c_char,
 }
 
+#[repr(C, packed)]
+pub struct CTextDrawPool {
+    pub m_bNotEmpty: [BOOL; 2304usize],
+    pub m_pObject: [*mut CTextDraw; 2304usize],
+}
+
+#[repr(C, packed)]
+pub struct CTextDraw {
+    pub m_szText: [::std::os::raw::c_char; 801usize],
+    pub m_szString: [::std::os::raw::c_char; 1602usize],
+    pub m_data: CTextDraw_Data,
+}
+
+#[repr(C, packed)]
+pub struct CTextDraw_Data {
+    pub m_fLetterWidth: f32,
+    pub m_fLetterHeight: f32,
+    pub m_letterColor: D3DCOLOR,
+    pub unknown: ::std::os::raw::c_uchar,
+    pub m_bCenter: ::std::os::raw::c_uchar,
+    pub m_bBox: ::std::os::raw::c_uchar,
+    pub m_fBoxSizeX: f32,
+    pub m_fBoxSizeY: f32,
+    pub m_boxColor: D3DCOLOR,
+    pub m_nProportional: ::std::os::raw::c_uchar,
+    pub m_backgroundColor: D3DCOLOR,
+    pub m_nShadow: ::std::os::raw::c_uchar,
+    pub m_nOutline: ::std::os::raw::c_uchar,
+    pub m_bLeft: ::std::os::raw::c_uchar,
+    pub m_bRight: ::std::os::raw::c_uchar,
+    pub m_nStyle: ::std::os::raw::c_int,
+    pub m_fX: f32,
+    pub m_fY: f32,
+    pub pad_0: [::std::os::raw::c_char; 8usize],
+    pub field_99B: ::std::os::raw::c_ulong,
+    pub field_99F: ::std::os::raw::c_ulong,
+    pub m_nIndex: ::std::os::raw::c_ulong,
+    pub m_bSelectable: ::std::os::raw::c_uchar,
+    pub m_nModel: ::std::os::raw::c_ushort,
+    pub m_rotation: CVector,
+    pub m_fZoom: f32,
+    pub m_aColor: [::std::os::raw::c_short; 2usize],
+    pub field_9BE: ::std::os::raw::c_uchar,
+    pub field_9BF: ::std::os::raw::c_uchar,
+    pub field_9C0: ::std::os::raw::c_uchar,
+    pub m_rect: [::std::os::raw::c_long; 4usize], // RECT hit-tested by CTextDrawSelection
+    pub field_9D1: ::std::os::raw::c_uchar,
+    pub field_9D2: ::std::os::raw::c_ulong,
+}
+
+impl CTextDraw {
+    pub fn info(&self, id: TextDrawId) -> TextDraw {
+        let data = &self.m_data;
+        let font = TextDrawFont::from_raw(data.m_nStyle);
+
+        let preview = if font == Some(TextDrawFont::ModelPreview) {
+            Some(PreviewModel {
+                model: data.m_nModel,
+                rotation: data.m_rotation,
+                zoom: data.m_fZoom,
+                colors: (data.m_aColor[0], data.m_aColor[1]),
+            })
+        } else {
+            None
+        };
+
+        TextDraw {
+            id,
+            text: encoding::decode_c_buffer(&{ self.m_szText }),
+            position: (data.m_fX, data.m_fY),
+            letter_size: (data.m_fLetterWidth, data.m_fLetterHeight),
+            letter_color: Rgba::from_abgr(data.m_letterColor as u32),
+            font,
+            alignment: TextDrawAlignment::from_flags(
+                data.m_bLeft != 0,
+                data.m_bCenter != 0,
+                data.m_bRight != 0,
+            ),
+            proportional: data.m_nProportional != 0,
+            shadow: data.m_nShadow,
+            outline: data.m_nOutline,
+            background_color: Rgba::from_abgr(data.m_backgroundColor as u32),
+            use_box: data.m_bBox != 0,
+            box_size: (data.m_fBoxSizeX, data.m_fBoxSizeY),
+            box_color: Rgba::from_abgr(data.m_boxColor as u32),
+            preview,
+            selectable: data.m_bSelectable != 0,
+            rect: ScreenRect::from_rect(data.m_rect),
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CGangZonePool {
+    pub m_pObject: [*mut CGangZone; 1024usize],
+    pub m_bNotEmpty: [BOOL; 1024usize],
+}
+
+#[repr(C, packed)]
+pub struct CGangZone {
+    pub m_fLeft: f32,
+    pub m_fBottom: f32,
+    pub m_fRight: f32,
+    pub m_fTop: f32,
+    pub m_color: D3DCOLOR,
+    pub m_altColor: D3DCOLOR,
+}
+
+impl CGangZone {
+    pub fn info(&self, id: u16) -> GangZone {
+        GangZone {
+            id,
+            min: (self.m_fLeft, self.m_fBottom),
+            max: (self.m_fRight, self.m_fTop),
+            color: Rgba::from_abgr(self.m_color as u32),
+            flashing: self.m_altColor != self.m_color,
+            flash_color: Rgba::from_abgr(self.m_altColor as u32),
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CPickupPool {
+    pub m_nCount: ::std::os::raw::c_int,
+    pub m_handle: [GTAREF; 4096usize],
+    pub m_nId: [::std::os::raw::c_int; 4096usize],
+    pub m_nTimer: [TICK; 4096usize],
+    pub m_weapon: [CPickupPool_WeaponPickup; 4096usize],
+    pub m_object: [CPickupPool_Pickup; 4096usize],
+}
+
+#[repr(C, packed)]
+pub struct CPickupPool_WeaponPickup {
+    pub m_bExists: bool,
+    pub m_nExOwner: ID,
+}
+
+#[repr(C, packed)]
+pub struct CPickupPool_Pickup {
+    pub m_nModel: ::std::os::raw::c_int,
+    pub m_nType: ::std::os::raw::c_int,
+    pub m_position: CVector,
+}
+
+impl CPickupPool {
+    pub fn pickup(&self, pickup_id: usize) -> Option<Pickup> {
+        if pickup_id >= 4096 || self.m_handle[pickup_id] == 0 {
+            return None;
+        }
+
+        let object = &self.m_object[pickup_id];
+
+        Some(Pickup {
+            id: pickup_id as i32,
+            model: object.m_nModel,
+            pickup_type: object.m_nType,
+            position: object.m_position,
+            handle: self.m_handle[pickup_id],
+        })
+    }
+}
+
+#[repr(C, packed)]
+pub struct CLabelPool {
+    pub m_object: [TextLabel; LABEL_POOL_SIZE],
+    pub m_bNotEmpty: [BOOL; LABEL_POOL_SIZE],
+}
+
+#[cfg(target_pointer_width = "32")]
+const _: () = assert!(std::mem::size_of::<TextLabel>() == 0x1D);
+#[cfg(target_pointer_width = "32")]
+const _: () = assert!(std::mem::size_of::<CLabelPool>() == (0x1D + 4) * 3074);
+
+#[repr(C, packed)]
+pub struct TextLabel {
+    pub m_pText: *mut ::std::os::raw::c_char,
+    pub m_color: D3DCOLOR,
+    pub m_position: CVector,
+    pub m_fDrawDistance: f32,
+    pub m_bTestLOS: bool,
+    pub m_nAttachedToPlayer: ID,
+    pub m_nAttachedToVehicle: ID,
+}
+
+impl TextLabel {
+    pub fn info(&self, id: u16) -> Label {
+        let attached = |id: ID| if id == 0xFFFF { None } else { Some(id) };
+
+        Label {
+            id,
+            text: encoding::decode_c_str(self.m_pText),
+            color: Rgba::from_argb(self.m_color as u32),
+            position: self.m_position,
+            draw_distance: self.m_fDrawDistance,
+            test_los: self.m_bTestLOS,
+            attached_player: attached(self.m_nAttachedToPlayer),
+            attached_vehicle: attached(self.m_nAttachedToVehicle),
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CActorPool {
+    pub m_nLargestId: ::std::os::raw::c_int,
+    pub m_pObject: [*mut CActor; 1000usize],
+    pub m_bNotEmpty: [BOOL; 1000usize],
+    pub m_pGameObject: [*mut GamePed; 1000usize], // GTA::CPed
+    pub pad_2ee4: [::std::os::raw::c_int; 1000usize],
+    pub pad_3e84: [::std::os::raw::c_int; 1000usize],
+}
+
+#[repr(C, packed)]
+pub struct CActor {
+    pub _base: CEntity,
+    pub m_pGamePed: *mut GamePed, // GTA::CPed
+    pub m_marker: GTAREF,
+    pub m_arrow: GTAREF,
+    pub m_bNeedsToCreateMarker: bool,
+    pub m_bInvulnerable: bool,
+}
+
+impl CActor {
+    pub fn gta_ped(&self) -> Option<&GamePed> {
+        if self.m_pGamePed.is_null() {
+            None
+        } else {
+            Some(unsafe { &*self.m_pGamePed })
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CMenuPool {
+    pub m_pObject: [*mut CMenu; 128usize],
+    pub m_bNotEmpty: [BOOL; 128usize],
+    pub m_nCurrent: NUMBER,
+    pub m_bCanceled: bool,
+}
+
+#[repr(C, packed)]
+pub struct CMenu {
+    pub m_nId: NUMBER,
+    pub m_szTitle: [::std::os::raw::c_char; 32usize],
+    pub m_szItems: [[[::std::os::raw::c_char; 32usize]; 2usize]; 12usize],
+    pub m_szHeader: [[::std::os::raw::c_char; 32usize]; 2usize],
+    pub m_fPosX: f32,
+    pub m_fPosY: f32,
+    pub m_fFirstColumnWidth: f32,
+    pub m_fSecondColumnWidth: f32,
+    pub m_nColumns: NUMBER,
+    pub m_interaction: CMenu_Interaction,
+    pub m_nColumnCount: [NUMBER; 2usize],
+    pub m_panel: GTAREF,
+}
+
+#[repr(C, packed)]
+pub struct CMenu_Interaction {
+    pub m_bMenu: BOOL,
+    pub m_bRow: [BOOL; 12usize],
+    pub m_bPadding: [BOOL; 3usize],
+}
+
+impl CMenu {
+    pub fn info(&self) -> Menu {
+        let column_count = (self.m_nColumns as usize).clamp(1, 2);
+        let widths = [self.m_fFirstColumnWidth, self.m_fSecondColumnWidth];
+        let items = self.m_szItems;
+        let headers = self.m_szHeader;
+        let interaction = &self.m_interaction;
+
+        let columns: Vec<MenuColumn> = (0..column_count)
+            .map(|column| {
+                let rows = (self.m_nColumnCount[column] as usize).min(12);
+
+                MenuColumn {
+                    header: encoding::decode_c_buffer(&headers[column]),
+                    width: widths[column],
+                    rows: (0..rows)
+                        .map(|row| encoding::decode_c_buffer(&items[row][column]))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let row_count = columns.iter().map(|column| column.rows.len()).max().unwrap_or(0);
+
+        Menu {
+            id: self.m_nId,
+            title: encoding::decode_c_buffer(&{ self.m_szTitle }),
+            position: (self.m_fPosX, self.m_fPosY),
+            columns,
+            enabled: interaction.m_bMenu != 0,
+            rows_enabled: (0..row_count).map(|row| interaction.m_bRow[row] != 0).collect(),
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct CDeathWindow {
+    pub m_bEnabled: BOOL,
+    pub m_entry: [CDeathWindow_Entry; 5usize],
+    pub m_nLongestNickWidth: ::std::os::raw::c_int,
+    pub m_position: [::std::os::raw::c_int; 2usize],
+    pub m_pFont: *mut (),        // ID3DXFont
+    pub m_pWeaponFont1: *mut (), // ID3DXFont
+    pub m_pWeaponFont2: *mut (), // ID3DXFont
+    pub m_pSprite: *mut (),      // ID3DXSprite
+    pub m_pDevice: *mut (),      // IDirect3DDevice9
+    pub m_bAuxFontInitialized: BOOL,
+    pub m_pAuxFont1: *mut (), // ID3DXFont
+    pub m_pAuxFont2: *mut (), // ID3DXFont
+}
+
+#[repr(C, packed)]
+pub struct CDeathWindow_Entry {
+    pub m_szKiller: [::std::os::raw::c_char; 25usize],
+    pub m_szVictim: [::std::os::raw::c_char; 25usize],
+    pub m_killerColor: D3DCOLOR,
+    pub m_victimColor: D3DCOLOR,
+    pub m_nWeapon: ::std::os::raw::c_char,
+}
+
+impl CDeathWindow_Entry {
+    pub fn event(&self) -> Option<KillEvent> {
+        let killer = encoding::decode_c_buffer(&{ self.m_szKiller });
+        let victim = encoding::decode_c_buffer(&{ self.m_szVictim });
+
+        if killer.is_empty() && victim.is_empty() {
+            return None;
+        }
+
+        Some(KillEvent::new(
+            killer,
+            self.m_killerColor as u32,
+            victim,
+            self.m_victimColor as u32,
+            self.m_nWeapon as u8,
+        ))
+    }
+}
+
+#[repr(C, packed)]
+pub struct CScoreboard {
+    pub m_bIsEnabled: BOOL,
+    pub m_nPlayerCount: ::std::os::raw::c_int,
+    pub m_position: [f32; 2usize],
+    pub m_fScalar: f32,
+    pub m_size: [f32; 2usize],
+    pub pad: [f32; 5usize],
+    pub m_pDevice: *mut (),           // IDirect3DDevice9
+    pub m_pDialog: *mut (),           // CDXUTDialog
+    pub m_pListBox: *mut DXUTListBox, // CDXUTListBox
+    pub m_nCurrentOffset: ::std::os::raw::c_int,
+    pub m_bIsSorted: BOOL,
+}
+
+#[repr(C, packed)]
+pub struct CChat {
+    pub m_nPageSize: ::std::os::raw::c_uint,
+    pub m_szLastMessage: *mut ::std::os::raw::c_char,
+    pub m_nMode: ::std::os::raw::c_int,
+    pub m_bTimestamps: bool,
+    pub m_bDoesLogExist: BOOL,
+    pub m_szLogPath: [::std::os::raw::c_char; 261usize],
+    pub m_pGameUi: *mut (),    // CDXUTDialog
+    pub m_pEditbox: *mut (),   // CDXUTEditBox
+    pub m_pScrollbar: *mut (), // CDXUTScrollBar
+    pub m_textColor: D3DCOLOR,
+    pub m_infoColor: D3DCOLOR,
+    pub m_debugColor: D3DCOLOR,
+    pub m_nWindowBottom: ::std::os::raw::c_long,
+    pub m_entry: [CChat_ChatEntry; 100usize],
+    pub m_pFontRenderer: *mut (),    // CFonts
+    pub m_pTextSprite: *mut (),      // ID3DXSprite
+    pub m_pSprite: *mut (),          // ID3DXSprite
+    pub m_pDevice: *mut (),          // IDirect3DDevice9
+    pub m_bRenderToSurface: BOOL,
+    pub m_pRenderToSurface: *mut (), // ID3DXRenderToSurface
+    pub m_pTexture: *mut (),         // IDirect3DTexture9
+    pub m_pSurface: *mut (),         // IDirect3DSurface9
+    pub m_displayMode: [::std::os::raw::c_uint; 4usize], // D3DDISPLAYMODE
+    pub pad_0: [::std::os::raw::c_int; 2usize],
+    pub m_bRedraw: BOOL,
+    pub m_nScrollbarPos: ::std::os::raw::c_long,
+    pub m_nCharHeight: ::std::os::raw::c_long,
+    pub m_nTimestampWidth: ::std::os::raw::c_long,
+}
+
+#[repr(C, packed)]
+pub struct CChat_ChatEntry {
+    pub m_timestamp: ::std::os::raw::c_long,
+    pub m_szPrefix: [::std::os::raw::c_char; 28usize],
+    pub m_szText: [::std::os::raw::c_char; 144usize],
+    pub unused: [::std::os::raw::c_char; 64usize],
+    pub m_nType: ::std::os::raw::c_int,
+    pub m_textColor: D3DCOLOR,
+    pub m_prefixColor: D3DCOLOR,
+}
+
+impl CChat_ChatEntry {
+    pub fn line(&self) -> Option<ChatLine> {
+        let entry_type = EntryType::from(self.m_nType);
+
+        if entry_type == EntryType::None {
+            return None;
+        }
+
+        Some(ChatLine::new(
+            entry_type,
+            self.m_timestamp as i32,
+            encoding::decode_c_buffer(&{ self.m_szPrefix }),
+            self.m_prefixColor as u32,
+            encoding::decode_c_buffer(&{ self.m_szText }),
+            self.m_textColor as u32,
+        ))
+    }
+}
+
 pub fn netgame() -> *mut CNetGame {
     unsafe { *(handle().add(CNETGAME) as *mut *mut CNetGame) }
 }
@@ -809,6 +1396,22 @@ pub fn player_pool() -> Option<&'static mut CPlayerPool> {
     }
 }
 
+/// Replaces the nickname samp sends on connect.
+pub fn set_local_player_name(name: &[u8], allocator: Option<&GameAllocator>) -> bool {
+    player_pool()
+        .map(|pool| {
+            let mut local_name = { pool.m_localInfo.m_szName };
+            let written = match allocator {
+                Some(allocator) => local_name.assign(name, allocator),
+                None => local_name.assign_inline(name),
+            };
+
+            pool.m_localInfo.m_szName = local_name;
+            written
+        })
+        .unwrap_or(false)
+}
+
 pub fn vehicle_pool() -> Option<&'static mut CVehiclePool> {
     unsafe {
         let pools = pools()?;
@@ -853,6 +1456,217 @@ pub fn find_object<'a>(object_id: i32) -> Option<&'a mut CObject> {
     }
 }
 
+pub fn textdraw_pool() -> Option<&'static mut CTextDrawPool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pTextDraw.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pTextDraw)
+    }
+}
+
+/// Pool slots with a textdraw, player textdraws start at 2048.
+pub fn textdraws<'a>() -> Option<impl Iterator<Item = (usize, &'a mut CTextDraw)>> {
+    textdraw_pool().map(|pool| {
+        let not_empty = pool.m_bNotEmpty;
+
+        {pool.m_pObject}
+            .iter()
+            .enumerate()
+            .filter(|(index, textdraw)| not_empty[*index] != 0 && !textdraw.is_null())
+            .map(|(index, textdraw)| (index, unsafe { &mut **textdraw }))
+            .collect::<Vec<(usize, &'a mut CTextDraw)>>()
+            .into_iter()
+    })
+}
+
+pub fn find_textdraw<'a>(index: usize) -> Option<&'a mut CTextDraw> {
+    let pool = textdraw_pool()?;
+
+    if index >= 2304 || pool.m_bNotEmpty[index] == 0 || pool.m_pObject[index].is_null() {
+        return None;
+    }
+
+    Some(unsafe { &mut *pool.m_pObject[index] })
+}
+
+pub fn gang_zone_pool() -> Option<&'static mut CGangZonePool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pGangZone.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pGangZone)
+    }
+}
+
+pub fn gang_zones<'a>() -> Option<impl Iterator<Item = (usize, &'a mut CGangZone)>> {
+    gang_zone_pool().map(|pool| {
+        let not_empty = pool.m_bNotEmpty;
+
+        {pool.m_pObject}
+            .iter()
+            .enumerate()
+            .filter(|(index, zone)| not_empty[*index] != 0 && !zone.is_null())
+            .map(|(index, zone)| (index, unsafe { &mut **zone }))
+            .collect::<Vec<(usize, &'a mut CGangZone)>>()
+            .into_iter()
+    })
+}
+
+pub fn find_gang_zone<'a>(zone_id: i32) -> Option<&'a mut CGangZone> {
+    if zone_id < 0 || zone_id >= 1024 {
+        return None;
+    }
+
+    let pool = gang_zone_pool()?;
+    let zone_id = zone_id as usize;
+
+    if pool.m_bNotEmpty[zone_id] == 0 || pool.m_pObject[zone_id].is_null() {
+        return None;
+    }
+
+    Some(unsafe { &mut *pool.m_pObject[zone_id] })
+}
+
+pub fn pickup_pool() -> Option<&'static mut CPickupPool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pPickup.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pPickup)
+    }
+}
+
+pub fn label_pool() -> Option<&'static mut CLabelPool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pLabel.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pLabel)
+    }
+}
+
+/// Pool slots with a label, player labels start at 2048.
+pub fn labels<'a>() -> Option<impl Iterator<Item = (usize, &'a mut TextLabel)>> {
+    label_pool().map(|pool| {
+        let not_empty = pool.m_bNotEmpty;
+
+        pool.m_object
+            .iter_mut()
+            .enumerate()
+            .filter(|(index, _)| not_empty[*index] != 0)
+            .collect::<Vec<(usize, &'a mut TextLabel)>>()
+            .into_iter()
+    })
+}
+
+pub fn actor_pool() -> Option<&'static mut CActorPool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pActor.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pActor)
+    }
+}
+
+pub fn actors<'a>() -> Option<impl Iterator<Item = (usize, &'a mut CActor)>> {
+    actor_pool().map(|pool| {
+        let not_empty = pool.m_bNotEmpty;
+
+        {pool.m_pObject}
+            .iter()
+            .enumerate()
+            .filter(|(index, actor)| not_empty[*index] != 0 && !actor.is_null())
+            .map(|(index, actor)| (index, unsafe { &mut **actor }))
+            .collect::<Vec<(usize, &'a mut CActor)>>()
+            .into_iter()
+    })
+}
+
+pub fn find_actor<'a>(actor_id: i32) -> Option<&'a mut CActor> {
+    if actor_id < 0 || actor_id >= 1000 {
+        return None;
+    }
+
+    let pool = actor_pool()?;
+    let actor_id = actor_id as usize;
+
+    if pool.m_bNotEmpty[actor_id] == 0 || pool.m_pObject[actor_id].is_null() {
+        return None;
+    }
+
+    Some(unsafe { &mut *pool.m_pObject[actor_id] })
+}
+
+pub fn menu_pool() -> Option<&'static mut CMenuPool> {
+    unsafe {
+        let pools = pools()?;
+
+        if pools.m_pMenu.is_null() {
+            return None;
+        }
+
+        Some(&mut *(*pools).m_pMenu)
+    }
+}
+
+/// Menu shown to the player right now.
+pub fn active_menu<'a>() -> Option<&'a mut CMenu> {
+    let pool = menu_pool()?;
+    let current = pool.m_nCurrent as usize;
+
+    if current >= 128 || pool.m_bNotEmpty[current] == 0 || pool.m_pObject[current].is_null() {
+        return None;
+    }
+
+    Some(unsafe { &mut *pool.m_pObject[current] })
+}
+
+pub fn deathwindow<'a>() -> Option<&'a mut CDeathWindow> {
+    let ptr = unsafe { *(handle().add(CDEATHWINDOW) as *mut *mut CDeathWindow) };
+
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { Some(&mut *ptr) }
+    }
+}
+
+pub fn scoreboard<'a>() -> Option<&'a mut CScoreboard> {
+    let ptr = unsafe { *(handle().add(CSCOREBOARD) as *mut *mut CScoreboard) };
+
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { Some(&mut *ptr) }
+    }
+}
+
+pub fn chat<'a>() -> Option<&'a mut CChat> {
+    let ptr = unsafe { *(handle().add(CCHAT) as *mut *mut CChat) };
+
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { Some(&mut *ptr) }
+    }
+}
+
 fn pools() -> Option<&'static mut CNetGame_Pools> {
     unsafe {
         let netgame = netgame();
@@ -0,0 +1,119 @@
+use super::version::{version, Version};
+use super::{v037 as r1, v037r3 as r3, v03dlr1 as dl};
+
+use crate::gta::color::{vehicle_color, Rgba};
+use crate::gta::entity::CEntity;
+use crate::gta::matrix::{CVector, RwMatrix};
+use crate::gta::vehicles::{vehicle_model, VehicleModel};
+
+/// GTA's `CVehicle`, read only as far as its `CEntity` base.
+#[repr(C, packed)]
+pub struct GameVehicle {
+    pad: [u8; 20],
+    pub matrix: *mut RwMatrix,
+}
+
+impl GameVehicle {
+    pub fn model_index(&self) -> u16 {
+        let entity = self as *const GameVehicle as *const CEntity;
+        unsafe { (*entity).m_nModelIndex }
+    }
+}
+
+pub struct Vehicle<'a> {
+    vehicle_v1: Option<&'a r1::CVehicle>,
+    vehicle_v3: Option<&'a r3::CVehicle>,
+    vehicle_dl: Option<&'a dl::CVehicle>,
+}
+
+impl<'a> Vehicle<'a> {
+    fn new_v1(vehicle: &'a r1::CVehicle) -> Vehicle<'a> {
+        Vehicle {
+            vehicle_v1: Some(vehicle),
+            vehicle_v3: None,
+            vehicle_dl: None,
+        }
+    }
+
+    fn new_v3(vehicle: &'a r3::CVehicle) -> Vehicle<'a> {
+        Vehicle {
+            vehicle_v3: Some(vehicle),
+            vehicle_v1: None,
+            vehicle_dl: None,
+        }
+    }
+
+    fn new_dl(vehicle: &'a dl::CVehicle) -> Vehicle<'a> {
+        Vehicle {
+            vehicle_dl: Some(vehicle),
+            vehicle_v1: None,
+            vehicle_v3: None,
+        }
+    }
+
+    pub fn get(vehicle_id: i32) -> Option<Vehicle<'a>> {
+        match version() {
+            Version::V037 => r1::find_vehicle(vehicle_id).map(|veh| Vehicle::new_v1(veh)),
+            Version::V037R3 => r3::find_vehicle(vehicle_id).map(|veh| Vehicle::new_v3(veh)),
+            Version::V03DLR1 => dl::find_vehicle(vehicle_id).map(|veh| Vehicle::new_dl(veh)),
+            _ => None,
+        }
+    }
+
+    pub fn game_vehicle(&self) -> Option<&'a GameVehicle> {
+        let v1 = self.vehicle_v1.map(|vehicle| vehicle.m_pGameVehicle);
+        let v3 = self.vehicle_v3.map(|vehicle| vehicle.m_pGameVehicle);
+        let dl = self.vehicle_dl.map(|vehicle| vehicle.m_pGameVehicle);
+
+        dl.or(v3).or(v1)
+            .filter(|ptr| !ptr.is_null())
+            .map(|ptr| unsafe { &*ptr })
+    }
+
+    pub fn matrix(&self) -> Option<RwMatrix> {
+        self.game_vehicle()
+            .filter(|vehicle| !vehicle.matrix.is_null())
+            .map(|vehicle| unsafe { vehicle.matrix.read() })
+    }
+
+    pub fn position(&self) -> CVector {
+        self.matrix()
+            .map(|matrix| matrix.pos)
+            .unwrap_or_else(|| CVector::zero())
+    }
+
+    pub fn model(&self) -> Option<u16> {
+        self.game_vehicle().map(|vehicle| vehicle.model_index())
+    }
+
+    pub fn model_info(&self) -> Option<&'static VehicleModel> {
+        self.model().and_then(|model| vehicle_model(model as i32))
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.model_info().map(|model| model.name)
+    }
+
+    /// Palette indices of the primary and secondary colors.
+    pub fn color_indices(&self) -> (u8, u8) {
+        let v1 = self
+            .vehicle_v1
+            .map(|veh| (veh.m_nPrimaryColor, veh.m_nSecondaryColor));
+        let v3 = self
+            .vehicle_v3
+            .map(|veh| (veh.m_nPrimaryColor, veh.m_nSecondaryColor));
+        let dl = self
+            .vehicle_dl
+            .map(|veh| (veh.m_nPrimaryColor, veh.m_nSecondaryColor));
+
+        dl.or(v3).or(v1).unwrap_or((0, 0))
+    }
+
+    pub fn primary_color(&self) -> Rgba {
+        vehicle_color(self.color_indices().0)
+    }
+
+    pub fn secondary_color(&self) -> Rgba {
+        vehicle_color(self.color_indices().1)
+    }
+}
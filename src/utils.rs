@@ -1,8 +1,11 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use winapi::shared::minwindef::{DWORD,
     // FARPROC,
     HMODULE};
 use winapi::um::libloaderapi::{GetModuleHandleA, GetProcAddress, LoadLibraryA};
+use winapi::um::memoryapi::VirtualQuery;
+use winapi::um::sysinfoapi::GetTickCount;
+use winapi::um::winnt::{MEMORY_BASIC_INFORMATION, MEM_COMMIT, PAGE_GUARD, PAGE_NOACCESS};
 use winapi::um::winuser::*;
 
 use crate::gta::matrix::CVector;
@@ -76,6 +79,128 @@ pub fn key_name(key: u32) -> String {
     }
 }
 
+/// `GetTickCount`, the clock samp times its connection attempts with.
+pub fn tick_count() -> u32 {
+    unsafe { GetTickCount() }
+}
+
+/// Whether `len` bytes at `ptr` are committed, readable memory, to look through
+/// structures whose layout is only guessed.
+pub fn is_readable(ptr: *const u8, len: usize) -> bool {
+    let mut address = ptr as usize;
+    let end = match address.checked_add(len) {
+        Some(end) if !ptr.is_null() => end,
+        _ => return false,
+    };
+
+    while address < end {
+        let mut info: MEMORY_BASIC_INFORMATION = unsafe { std::mem::zeroed() };
+        let size = std::mem::size_of::<MEMORY_BASIC_INFORMATION>();
+
+        if unsafe { VirtualQuery(address as *const _, &mut info, size) } == 0 {
+            return false;
+        }
+
+        if info.State != MEM_COMMIT || info.Protect & (PAGE_NOACCESS | PAGE_GUARD) != 0 {
+            return false;
+        }
+
+        address = info.BaseAddress as usize + info.RegionSize;
+    }
+
+    true
+}
+
+/// Rough check that `ptr` is where a function starts: MSVC pads between functions with
+/// `int3`/`nop`, otherwise the previous function ends right before with a `ret`.
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // only read after `is_readable`
+pub fn is_function_start(ptr: *const u8) -> bool {
+    if !is_readable(ptr.wrapping_sub(3), 4) {
+        return false;
+    }
+
+    unsafe {
+        let first = *ptr;
+        let previous = *ptr.sub(1);
+        let ret_imm16 = *ptr.sub(3) == 0xC2;
+
+        first != 0xCC && first != 0x00 && (matches!(previous, 0xCC | 0x90 | 0xC3) || ret_imm16)
+    }
+}
+
+/// A function a module imports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// DLL it's imported from, as written in the import table.
+    pub dll: String,
+    /// Where the loader bound it.
+    pub address: *const (),
+}
+
+/// Where `module` imports `name` from, read from the import table of the loaded PE32
+/// image. Imports by ordinal and import tables without names are skipped.
+///
+/// # Safety
+///
+/// `module` has to be null or the base of a module mapped by the loader.
+pub unsafe fn find_import(module: *const u8, name: &str) -> Option<Import> {
+    if module.is_null() {
+        return None;
+    }
+
+    let read_u16 = |offset: usize| (module.add(offset) as *const u16).read_unaligned();
+    let read = |offset: usize| (module.add(offset) as *const u32).read_unaligned() as usize;
+
+    // MZ, PE\0\0 and the PE32 optional header magic
+    if read_u16(0) != 0x5A4D {
+        return None;
+    }
+
+    let nt = read(0x3C);
+
+    if read(nt) != 0x4550 || read_u16(nt + 24) != 0x10B {
+        return None;
+    }
+
+    // second data directory of the optional header
+    let mut descriptor = match read(nt + 24 + 104) {
+        0 => return None,
+        imports => imports,
+    };
+
+    while read(descriptor + 12) != 0 {
+        let names = read(descriptor);
+        let addresses = read(descriptor + 16);
+
+        for index in (0..).take_while(|_| names != 0) {
+            let thunk = read(names + index * 4);
+
+            if thunk == 0 {
+                break;
+            }
+
+            if thunk & 0x8000_0000 != 0 {
+                continue;
+            }
+
+            let import = CStr::from_ptr(module.add(thunk + 2) as *const _);
+
+            if import.to_bytes() == name.as_bytes() {
+                let dll = CStr::from_ptr(module.add(read(descriptor + 12)) as *const _);
+
+                return Some(Import {
+                    dll: dll.to_string_lossy().into_owned(),
+                    address: read(addresses + index * 4) as *const (),
+                });
+            }
+        }
+
+        descriptor += 20;
+    }
+
+    None
+}
+
 pub fn find_function<T>(module: &str, func: &str) -> Option<T> {
     let module = CString::new(module).unwrap();
     let func = CString::new(func).unwrap();
@@ -116,3 +241,70 @@ pub struct FixedFileInfo {
     file_date_ms: DWORD,
     file_date_ls: DWORD,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(image: &mut [u8], offset: usize, value: u32) {
+        image[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// PE32 headers at 0x40, import descriptors at 0x200, names from 0x300.
+    fn image() -> Vec<u8> {
+        let mut image = vec![0u8; 0x400];
+
+        image[..2].copy_from_slice(b"MZ");
+        write(&mut image, 0x3C, 0x40);
+        image[0x40..0x44].copy_from_slice(b"PE\0\0");
+        image[0x40 + 24..0x40 + 26].copy_from_slice(&0x10Bu16.to_le_bytes());
+        write(&mut image, 0x40 + 24 + 104, 0x200);
+
+        // ws2_32: an ordinal import; msvcr: malloc and free
+        write(&mut image, 0x200, 0x280);
+        write(&mut image, 0x200 + 12, 0x3E0);
+        write(&mut image, 0x200 + 16, 0x2C0);
+        write(&mut image, 0x214, 0x290);
+        write(&mut image, 0x214 + 12, 0x3F0);
+        write(&mut image, 0x214 + 16, 0x2D0);
+
+        write(&mut image, 0x280, 0x8000_0017);
+        write(&mut image, 0x290, 0x300);
+        write(&mut image, 0x294, 0x310);
+        write(&mut image, 0x2C0, 0x1111);
+        write(&mut image, 0x2D0, 0xAAAA);
+        write(&mut image, 0x2D4, 0xBBBB);
+
+        image[0x302..0x308].copy_from_slice(b"malloc");
+        image[0x312..0x316].copy_from_slice(b"free");
+        image[0x3E0..0x3EA].copy_from_slice(b"WS2_32.dll");
+        image[0x3F0..0x3FB].copy_from_slice(b"MSVCR80.dll");
+        image
+    }
+
+    #[test]
+    fn imports() {
+        let image = image();
+        let import = |name| {
+            unsafe { find_import(image.as_ptr(), name) }
+                .map(|import| (import.dll, import.address as usize))
+        };
+
+        assert_eq!(import("malloc"), Some(("MSVCR80.dll".to_owned(), 0xAAAA)));
+        assert_eq!(import("free"), Some(("MSVCR80.dll".to_owned(), 0xBBBB)));
+        assert_eq!(import("realloc"), None);
+        assert_eq!(import("mallo"), None);
+        assert_eq!(unsafe { find_import(std::ptr::null(), "malloc") }, None);
+    }
+
+    #[test]
+    fn not_an_image() {
+        let mut image = image();
+        image[0x40] = b'N';
+
+        unsafe {
+            assert_eq!(find_import(image.as_ptr(), "malloc"), None);
+            assert_eq!(find_import([0u8; 0x100].as_ptr(), "malloc"), None);
+        }
+    }
+}